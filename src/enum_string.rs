@@ -1,29 +1,230 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0+
 
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{quote, quote_spanned};
 
+use crate::match_mode::MatchMode;
+use crate::name_format::NameFormat;
 use crate::string_transform::StringTransform;
 use syn::export::TokenStream2;
 
+#[derive(Default)]
 pub(crate) struct EnumStringOptions {
     transform: Option<StringTransform>,
+    serde_rename_all: bool,
     prepend_enum_name: bool,
+    split_digits: bool,
+    prefix: String,
+    suffix: String,
+    name_format: Option<NameFormat>,
+    transform_affixes: bool,
+    on_unknown: Option<syn::Path>,
+    matching: MatchMode,
+    impl_display: bool,
+    impl_from_str: bool,
+    impl_as_str: bool,
+    impl_variants: bool,
+    repr: Option<syn::Ident>,
+    dual_input: Option<DualInputCanonical>,
+    compact: bool,
+    unit_variant: bool,
+    suggest: bool,
+    serde_path: Option<syn::Path>,
+    with_module: Option<syn::Ident>,
+    skip_serde_impls: bool,
 }
 
-impl Default for EnumStringOptions {
-    fn default() -> Self {
-        Self {
-            transform: None,
-            prepend_enum_name: false,
-        }
+/// The form serialized when both strings and discriminants are accepted for deserialization
+pub(crate) enum DualInputCanonical {
+    String,
+    Repr,
+}
+
+#[derive(Default)]
+pub(crate) struct VariantStringOptions {
+    rename: Option<String>,
+    aliases: Vec<String>,
+    other: bool,
+    default: bool,
+    skip: bool,
+}
+
+struct EnumStringVariant {
+    ident: syn::Ident,
+    name: String,
+    aliases: Vec<String>,
+    cfgs: Vec<syn::Attribute>,
+}
+
+/// A variant left out of serialization and deserialization
+struct SkippedVariant {
+    ident: syn::Ident,
+    cfgs: Vec<syn::Attribute>,
+}
+
+const ENUM_KEYS: &[&str] = &[
+    "transform",
+    "matching",
+    "repr",
+    "dual_input",
+    "crate",
+    "on_unknown",
+    "prefix",
+    "suffix",
+    "name_format",
+    "with_module",
+];
+const ENUM_KEYWORDS: &[&str] = &[
+    "prepend_enum_name",
+    "split_digits",
+    "transform_affixes",
+    "display",
+    "from_str",
+    "as_str",
+    "variants",
+    "compact",
+    "unit_variant",
+    "suggest",
+    "skip_serde_impls",
+];
+const VARIANT_KEYS: &[&str] = &["rename", "alias"];
+const VARIANT_KEYWORDS: &[&str] = &["other", "default", "fallback", "skip"];
+const SERDE_ENUM_KEYS: &[&str] = &["rename_all", "crate"];
+const SERDE_VARIANT_KEYS: &[&str] = &["rename", "alias"];
+const SERDE_VARIANT_KEYWORDS: &[&str] = &["skip", "other"];
+const REPR_TYPES: &[&str] = &["u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64"];
+const DUAL_INPUT_FORMS: &[&str] = &["string", "repr"];
+
+/// Get the string literal passed for `key`, reporting an error if it's some other literal.
+fn expect_str_lit<'a>(
+    key: &str,
+    lit: &'a syn::Lit,
+    errors: &mut crate::helpers::Errors,
+) -> Option<&'a syn::LitStr> {
+    if let syn::Lit::Str(value_lit) = lit {
+        Some(value_lit)
+    } else {
+        errors.error_spanned(
+            lit,
+            format!("Invalid parameter passed for '{}', string expected", key),
+        );
+        None
     }
 }
 
+/// Report an unrecognised `key`, hinting at the closest known one. `takes_value` says
+/// whether the key was given a value, and `other_kind` holds the keys which take the
+/// opposite, so that a known key used the wrong way gets a more helpful message.
+fn unknown_key(
+    key: &syn::Ident,
+    attr_name: &str,
+    takes_value: bool,
+    candidates: &[&str],
+    other_kind: &[&str],
+    errors: &mut crate::helpers::Errors,
+) {
+    let key_name = key.to_string();
+
+    let message = if other_kind.contains(&&*key_name) {
+        if takes_value {
+            format!("'{}' in #[{}] does not take a value", key_name, attr_name)
+        } else {
+            format!("'{}' in #[{}] requires a value", key_name, attr_name)
+        }
+    } else {
+        format!(
+            "Unknown {} '{}' in #[{}]{}",
+            if takes_value { "key" } else { "keyword" },
+            key_name,
+            attr_name,
+            crate::helpers::did_you_mean(&key_name, candidates)
+        )
+    };
+
+    errors.error_spanned(key, message);
+}
+
 impl EnumStringOptions {
-    pub(crate) fn from_attr_params(params: &[syn::NestedMeta]) -> Self {
+    fn apply_transform(&self, input: &str) -> String {
+        match &self.transform {
+            Some(transform) if self.serde_rename_all => transform.transform_like_serde(input),
+            Some(transform) => transform.transform(input, self.split_digits),
+            None => input.to_string(),
+        }
+    }
+
+    /// Apply the transform to each run of letters, digits and word separators in `input`,
+    /// keeping any other punctuation, such as the `.` in `color.`, verbatim between them.
+    fn apply_transform_around_punctuation(&self, input: &str) -> String {
+        let is_word_char =
+            |chr: char| chr.is_alphanumeric() || chr == '_' || chr == '-' || chr.is_whitespace();
+
+        let mut out = String::new();
+        let mut rest = input;
+
+        while !rest.is_empty() {
+            let word_end = rest.find(|chr| !is_word_char(chr)).unwrap_or(rest.len());
+            out.push_str(&self.apply_transform(&rest[..word_end]));
+            rest = &rest[word_end..];
+
+            let punctuation_end = rest.find(is_word_char).unwrap_or(rest.len());
+            out.push_str(&rest[..punctuation_end]);
+            rest = &rest[punctuation_end..];
+        }
+
+        out
+    }
+
+    /// Build the string for a variant which hasn't been renamed. The prefix, suffix and any
+    /// literal text from `name_format` are kept verbatim unless `transform_affixes` is set, in
+    /// which case the transform is applied to the assembled string as a whole, apart from its
+    /// punctuation.
+    fn variant_string(&self, enum_name: &str, variant_name: &str) -> String {
+        let name = match &self.name_format {
+            Some(name_format) if self.transform_affixes => {
+                name_format.render(enum_name, variant_name, str::to_string)
+            }
+            Some(name_format) => {
+                name_format.render(enum_name, variant_name, |name| self.apply_transform(name))
+            }
+            None if self.prepend_enum_name => {
+                let mut out = String::new();
+                out.push_str(enum_name);
+                out.push_str(variant_name);
+
+                out
+            }
+            None => variant_name.to_string(),
+        };
+
+        if self.transform_affixes {
+            self.apply_transform_around_punctuation(&format!(
+                "{}{}{}",
+                self.prefix, name, self.suffix
+            ))
+        } else if self.name_format.is_some() {
+            format!("{}{}{}", self.prefix, name, self.suffix)
+        } else {
+            format!(
+                "{}{}{}",
+                self.prefix,
+                self.apply_transform(&name),
+                self.suffix
+            )
+        }
+    }
+
+    pub(crate) fn from_attr_params(
+        macro_name: &str,
+        params: &[syn::NestedMeta],
+        errors: &mut crate::helpers::Errors,
+    ) -> Self {
         let mut options: EnumStringOptions = Default::default();
-        //        let mut
+        let mut dual_input_key: Option<&syn::Ident> = None;
+        let mut compact_key: Option<&syn::Ident> = None;
+        let mut unit_variant_key: Option<&syn::Ident> = None;
+        let mut name_format_lit: Option<&syn::LitStr> = None;
+
         for param in params {
             use syn::Meta::*;
             use syn::NestedMeta::*;
@@ -31,23 +232,129 @@ impl EnumStringOptions {
             match *param {
                 Meta(NameValue(ref value)) => {
                     let key_name = value.ident.to_string();
+                    let value_lit = match &*key_name {
+                        key if ENUM_KEYS.contains(&key) => {
+                            match expect_str_lit(key, &value.lit, errors) {
+                                Some(value_lit) => value_lit,
+                                None => continue,
+                            }
+                        }
+                        _ => {
+                            unknown_key(
+                                &value.ident,
+                                macro_name,
+                                true,
+                                ENUM_KEYS,
+                                ENUM_KEYWORDS,
+                                errors,
+                            );
+                            continue;
+                        }
+                    };
+                    let value_str = value_lit.value();
+
                     match &*key_name {
                         "transform" => {
-                            use syn::Lit::*;
-
-                            if let Str(value_lit) = &value.lit {
-                                if let Some(transform) =
-                                    StringTransform::from_str(&*value_lit.value())
-                                {
-                                    options.transform = Some(transform);
-                                } else {
-                                    panic!("'{}' is not a valid string transformation type for 'transform'", &*value_lit.value());
-                                }
+                            if let Some(transform) = StringTransform::from_str(&value_str) {
+                                options.transform = Some(transform);
+                            } else {
+                                errors.error_spanned(
+                                    value_lit,
+                                    format!(
+                                        "'{}' is not a valid string transformation type for 'transform'{}",
+                                        value_str,
+                                        crate::helpers::did_you_mean(&value_str, StringTransform::NAMES)
+                                    ),
+                                );
+                            }
+                        }
+                        "matching" => {
+                            if let Some(matching) = MatchMode::from_str(&value_str) {
+                                options.matching = matching;
                             } else {
-                                panic!("Invalid paramater passed for 'transform', string expected");
+                                errors.error_spanned(
+                                    value_lit,
+                                    format!(
+                                        "'{}' is not a valid matching mode for 'matching'{}",
+                                        value_str,
+                                        crate::helpers::did_you_mean(&value_str, MatchMode::NAMES)
+                                    ),
+                                );
                             }
                         }
-                        _ => panic!("Unknown key '{}' in #[serde_enum_string]", &*key_name),
+                        "repr" => {
+                            if REPR_TYPES.contains(&&*value_str) {
+                                options.repr = Some(syn::Ident::new(&value_str, value_lit.span()));
+                            } else {
+                                errors.error_spanned(
+                                    value_lit,
+                                    format!(
+                                        "'{}' is not a valid integer type for 'repr'{}",
+                                        value_str,
+                                        crate::helpers::did_you_mean(&value_str, REPR_TYPES)
+                                    ),
+                                );
+                            }
+                        }
+                        "dual_input" => {
+                            dual_input_key = Some(&value.ident);
+                            options.dual_input = match &*value_str {
+                                "string" => Some(DualInputCanonical::String),
+                                "repr" => Some(DualInputCanonical::Repr),
+                                _ => {
+                                    errors.error_spanned(
+                                        value_lit,
+                                        format!(
+                                            "'{}' is not a valid form for 'dual_input', expected \"string\" or \"repr\"{}",
+                                            value_str,
+                                            crate::helpers::did_you_mean(&value_str, DUAL_INPUT_FORMS)
+                                        ),
+                                    );
+                                    None
+                                }
+                            };
+                        }
+                        "prefix" => {
+                            options.prefix = value_str;
+                        }
+                        "suffix" => {
+                            options.suffix = value_str;
+                        }
+                        "name_format" => match NameFormat::parse(&value_str) {
+                            Ok(name_format) => {
+                                name_format_lit = Some(value_lit);
+                                options.name_format = Some(name_format);
+                            }
+                            Err(message) => errors.error_spanned(value_lit, message),
+                        },
+                        "with_module" => match value_lit.parse::<syn::Ident>() {
+                            Ok(ident) => options.with_module = Some(ident),
+                            Err(_) => errors.error_spanned(
+                                value_lit,
+                                format!(
+                                    "'{}' is not a valid module name for 'with_module'",
+                                    value_str
+                                ),
+                            ),
+                        },
+                        "crate" => match value_lit.parse::<syn::Path>() {
+                            Ok(path) => options.serde_path = Some(path),
+                            Err(_) => errors.error_spanned(
+                                value_lit,
+                                format!("'{}' is not a valid path for 'crate'", value_str),
+                            ),
+                        },
+                        "on_unknown" => match value_lit.parse::<syn::Path>() {
+                            Ok(path) => options.on_unknown = Some(path),
+                            Err(_) => errors.error_spanned(
+                                value_lit,
+                                format!(
+                                    "'{}' is not a valid function path for 'on_unknown'",
+                                    value_str
+                                ),
+                            ),
+                        },
+                        _ => unreachable!(),
                     }
                 }
                 Meta(Word(ref ident)) => {
@@ -56,149 +363,1801 @@ impl EnumStringOptions {
                         "prepend_enum_name" => {
                             options.prepend_enum_name = true;
                         }
-                        _ => panic!("Unknown keyword '{}' in #[serde_enum_string]", &*keyword),
+                        "split_digits" => {
+                            options.split_digits = true;
+                        }
+                        "transform_affixes" => {
+                            options.transform_affixes = true;
+                        }
+                        "skip_serde_impls" => {
+                            options.skip_serde_impls = true;
+                        }
+                        "display" => {
+                            options.impl_display = true;
+                        }
+                        "from_str" => {
+                            options.impl_from_str = true;
+                        }
+                        "as_str" => {
+                            options.impl_as_str = true;
+                        }
+                        "variants" => {
+                            options.impl_variants = true;
+                        }
+                        "compact" => {
+                            compact_key = Some(ident);
+                            options.compact = true;
+                        }
+                        "unit_variant" => {
+                            unit_variant_key = Some(ident);
+                            options.unit_variant = true;
+                        }
+                        "suggest" => {
+                            options.suggest = true;
+                        }
+                        _ => {
+                            unknown_key(ident, macro_name, false, ENUM_KEYWORDS, ENUM_KEYS, errors)
+                        }
                     }
                 }
-                ref unknown => panic!(
-                    "Unknown syntax element found in #[serde_enum_string]: {:?}",
-                    unknown
+                ref unknown => errors.error_spanned(
+                    unknown,
+                    format!("Unknown syntax element found in #[{}]", macro_name),
                 ),
             }
         }
 
+        if let Some(name_format_lit) = name_format_lit {
+            if options.prepend_enum_name {
+                errors.error_spanned(
+                    name_format_lit,
+                    "'name_format' and 'prepend_enum_name' cannot be used together",
+                );
+            }
+        }
+
+        if let Some(dual_input_key) = dual_input_key {
+            if options.repr.is_none() {
+                errors.error_spanned(dual_input_key, "'dual_input' requires 'repr' to be set");
+            }
+
+            if options.compact {
+                errors.error_spanned(
+                    compact_key,
+                    "'compact' and 'dual_input' cannot be used together",
+                );
+            }
+        }
+
+        if let Some(unit_variant_key) = unit_variant_key {
+            if options.repr.is_some() || options.compact {
+                errors.error_spanned(
+                    unit_variant_key,
+                    "'unit_variant' cannot be used with 'repr' or 'compact'",
+                );
+            }
+        }
+
         options
     }
 }
 
-pub(crate) fn serde_enum_string_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
-    let macro_name = "serde_enum_string";
+impl EnumStringOptions {
+    /// Honour the subset of serde's own container attributes which make sense here, so that
+    /// an `enum` can be migrated from `#[derive(Serialize, Deserialize)]` unchanged.
+    pub(crate) fn apply_serde_attrs(
+        &mut self,
+        attrs: &[syn::Attribute],
+        errors: &mut crate::helpers::Errors,
+    ) {
+        for param in crate::helpers::attr_params("serde", attrs, errors) {
+            use syn::Meta::*;
+            use syn::NestedMeta::*;
+
+            let value = match param {
+                Meta(NameValue(ref value))
+                    if SERDE_ENUM_KEYS.contains(&&*value.ident.to_string()) =>
+                {
+                    value
+                }
+                Meta(NameValue(ref value)) => {
+                    unsupported_serde_key(&value.ident, SERDE_ENUM_KEYS, errors);
+                    continue;
+                }
+                Meta(Word(ref ident)) | Meta(List(syn::MetaList { ref ident, .. })) => {
+                    unsupported_serde_key(ident, SERDE_ENUM_KEYS, errors);
+                    continue;
+                }
+                ref unknown => {
+                    errors.error_spanned(unknown, "Unknown syntax element found in #[serde]");
+                    continue;
+                }
+            };
+
+            let key_name = value.ident.to_string();
+            let value_lit = match expect_str_lit(&key_name, &value.lit, errors) {
+                Some(value_lit) => value_lit,
+                None => continue,
+            };
+            let value_str = value_lit.value();
+
+            match &*key_name {
+                "rename_all" => {
+                    if self.transform.is_some() {
+                        errors.error_spanned(
+                            &value.ident,
+                            "'rename_all' in #[serde] cannot be combined with 'transform'",
+                        );
+                    } else if StringTransform::SERDE_NAMES.contains(&&*value_str) {
+                        self.transform = StringTransform::from_str(&value_str);
+                        self.serde_rename_all = true;
+                    } else {
+                        errors.error_spanned(
+                            value_lit,
+                            format!(
+                                "'{}' is not a valid string transformation type for 'rename_all'{}",
+                                value_str,
+                                crate::helpers::did_you_mean(
+                                    &value_str,
+                                    StringTransform::SERDE_NAMES
+                                )
+                            ),
+                        );
+                    }
+                }
+                "crate" => {
+                    if self.serde_path.is_some() {
+                        errors.error_spanned(
+                            &value.ident,
+                            "'crate' in #[serde] cannot be combined with 'crate' in the macro options",
+                        );
+                    } else {
+                        match value_lit.parse::<syn::Path>() {
+                            Ok(path) => self.serde_path = Some(path),
+                            Err(_) => errors.error_spanned(
+                                value_lit,
+                                format!("'{}' is not a valid path for 'crate'", value_str),
+                            ),
+                        }
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+}
+
+/// Report a key of serde's own attribute which serde understands, but which isn't supported
+fn unsupported_serde_key(
+    key: &syn::Ident,
+    candidates: &[&str],
+    errors: &mut crate::helpers::Errors,
+) {
+    let key_name = key.to_string();
+
+    errors.error_spanned(
+        key,
+        format!(
+            "'{}' in #[serde] is not supported by serde_json_helpers{}",
+            key_name,
+            crate::helpers::did_you_mean(&key_name, candidates)
+        ),
+    );
+}
+
+impl VariantStringOptions {
+    pub(crate) fn from_attrs(
+        attrs: &[syn::Attribute],
+        errors: &mut crate::helpers::Errors,
+    ) -> Self {
+        let mut options: VariantStringOptions = Default::default();
+
+        for param in crate::helpers::attr_params("enum_string", attrs, errors) {
+            use syn::Meta::*;
+            use syn::NestedMeta::*;
+
+            match param {
+                Meta(NameValue(ref value)) => {
+                    let key_name = value.ident.to_string();
+                    match &*key_name {
+                        "rename" => {
+                            if let Some(value_lit) = expect_str_lit(&key_name, &value.lit, errors) {
+                                options.rename = Some(value_lit.value());
+                            }
+                        }
+                        "alias" => {
+                            if let Some(value_lit) = expect_str_lit(&key_name, &value.lit, errors) {
+                                options.aliases.push(value_lit.value());
+                            }
+                        }
+                        _ => unknown_key(
+                            &value.ident,
+                            "enum_string",
+                            true,
+                            VARIANT_KEYS,
+                            VARIANT_KEYWORDS,
+                            errors,
+                        ),
+                    }
+                }
+                Meta(Word(ref ident)) => {
+                    let keyword = ident.to_string();
+                    match &*keyword {
+                        "other" => {
+                            options.other = true;
+                        }
+                        "default" | "fallback" => {
+                            options.default = true;
+                        }
+                        "skip" => {
+                            options.skip = true;
+                        }
+                        _ => unknown_key(
+                            ident,
+                            "enum_string",
+                            false,
+                            VARIANT_KEYWORDS,
+                            VARIANT_KEYS,
+                            errors,
+                        ),
+                    }
+                }
+                ref unknown => {
+                    errors.error_spanned(unknown, "Unknown syntax element found in #[enum_string]")
+                }
+            }
+        }
+
+        options
+    }
+
+    /// Honour the subset of serde's own variant attributes which make sense here. serde's
+    /// `other` is the same as `default`.
+    pub(crate) fn apply_serde_attrs(
+        &mut self,
+        attrs: &[syn::Attribute],
+        errors: &mut crate::helpers::Errors,
+    ) {
+        for param in crate::helpers::attr_params("serde", attrs, errors) {
+            use syn::Meta::*;
+            use syn::NestedMeta::*;
+
+            match param {
+                Meta(NameValue(ref value)) => {
+                    let key_name = value.ident.to_string();
+
+                    if !SERDE_VARIANT_KEYS.contains(&&*key_name) {
+                        unsupported_serde_key(&value.ident, SERDE_VARIANT_KEYS, errors);
+                        continue;
+                    }
+
+                    let value_lit = match expect_str_lit(&key_name, &value.lit, errors) {
+                        Some(value_lit) => value_lit,
+                        None => continue,
+                    };
+
+                    if key_name == "alias" {
+                        self.aliases.push(value_lit.value());
+                    } else if self.rename.is_some() {
+                        errors.error_spanned(
+                            &value.ident,
+                            "'rename' in #[serde] cannot be combined with 'rename' in #[enum_string]",
+                        );
+                    } else {
+                        self.rename = Some(value_lit.value());
+                    }
+                }
+                Meta(Word(ref ident)) => match &*ident.to_string() {
+                    "skip" => {
+                        self.skip = true;
+                    }
+                    "other" => {
+                        self.default = true;
+                    }
+                    _ => unsupported_serde_key(ident, SERDE_VARIANT_KEYWORDS, errors),
+                },
+                Meta(List(ref meta_list)) => {
+                    unsupported_serde_key(&meta_list.ident, SERDE_VARIANT_KEYS, errors)
+                }
+                ref unknown => {
+                    errors.error_spanned(unknown, "Unknown syntax element found in #[serde]")
+                }
+            }
+        }
+    }
+}
 
+pub(crate) fn serde_enum_string_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
     let item_input: syn::ItemEnum = syn::parse_macro_input!(item as syn::ItemEnum);
     let attr_input: Vec<syn::NestedMeta> = syn::parse_macro_input!(attr as syn::AttributeArgs);
 
+    expand("serde_enum_string", attr_input, item_input, true).into()
+}
+
+pub(crate) fn derive_serde_enum_string_impl(item: TokenStream) -> TokenStream {
+    let item_input: syn::ItemEnum = syn::parse_macro_input!(item as syn::ItemEnum);
+
+    expand("derive(SerdeEnumString)", Vec::new(), item_input, false).into()
+}
+
+/// Generate the `impl`s for an `enum`, taking options both from `attr_input` and from any
+/// `#[enum_string(...)]` attributes on the `enum` itself. The attribute macro needs the `enum`
+/// re-emitted with those attributes stripped (`emit_item`), whereas a derive must not emit it.
+fn expand(
+    macro_name: &str,
+    mut attr_input: Vec<syn::NestedMeta>,
+    mut item_input: syn::ItemEnum,
+    emit_item: bool,
+) -> TokenStream2 {
     // Get type name and identifier
-    let enum_ident = &item_input.ident;
+    let enum_ident = item_input.ident.clone();
     let enum_name = enum_ident.to_string();
+    let generics = item_input.generics.clone();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    // Deny use of existing serialize/deserialize impls
-    crate::helpers::guard_against_serde_derives(macro_name, &*enum_name, &item_input.attrs);
+    let mut errors = crate::helpers::Errors::default();
 
-    let options = EnumStringOptions::from_attr_params(&*attr_input);
+    attr_input.extend(crate::helpers::attr_params(
+        "enum_string",
+        &item_input.attrs,
+        &mut errors,
+    ));
+    item_input
+        .attrs
+        .retain(|attr| !crate::helpers::is_enum_string_attr(attr));
 
-    let variants = crate::helpers::get_enum_variant_names(&item_input);
+    let mut options = EnumStringOptions::from_attr_params(macro_name, &attr_input, &mut errors);
 
-    let variants: Vec<(syn::Ident, String)> = variants
-        .into_iter()
-        .map(|v| {
-            if options.prepend_enum_name {
-                let mut out = String::new();
-                out.push_str(&*enum_name);
-                out.push_str(&*v.1);
+    // Helper attributes can only be read here if they're unconditional
+    let helper_attrs: &[&str] = if options.skip_serde_impls {
+        &["enum_string"]
+    } else {
+        &["enum_string", "serde"]
+    };
+    crate::helpers::guard_against_cfg_attrs(
+        macro_name,
+        helper_attrs,
+        &item_input.attrs,
+        &mut errors,
+    );
 
-                (v.0, out)
-            } else {
-                v
+    // Without our own impls, the enum may well have serde's, and its attributes belong to them
+    if !options.skip_serde_impls {
+        // Deny use of existing serialize/deserialize impls
+        crate::helpers::guard_against_serde_derives(
+            macro_name,
+            &enum_name,
+            &item_input.attrs,
+            &mut errors,
+        );
+
+        options.apply_serde_attrs(&item_input.attrs, &mut errors);
+        item_input
+            .attrs
+            .retain(|attr| !crate::helpers::is_serde_attr(attr));
+    }
+
+    if options.with_module.is_some() && !item_input.generics.params.is_empty() {
+        errors.error_spanned(
+            &item_input.generics,
+            "'with_module' cannot be used with generic enums",
+        );
+    }
+
+    // Generated code refers to serde through this path, in case it's been renamed or re-exported
+    let serde = options
+        .serde_path
+        .clone()
+        .unwrap_or_else(|| syn::parse_quote! { ::serde });
+
+    // Collect per-variant options, stripping the helper attributes so they
+    // don't end up in the emitted enum
+    let mut variants: Vec<EnumStringVariant> = Vec::new();
+    let mut other_variant: Option<syn::Ident> = None;
+    let mut default_variant: Option<syn::Ident> = None;
+    let mut skipped_variants: Vec<SkippedVariant> = Vec::new();
+
+    for variant in item_input.variants.iter_mut() {
+        let variant_name = variant.ident.to_string();
+
+        // Anything generated for a conditionally compiled variant must be just as conditional
+        let cfgs = crate::helpers::cfg_attrs(&variant.attrs);
+        crate::helpers::guard_against_cfg_attrs(
+            macro_name,
+            helper_attrs,
+            &variant.attrs,
+            &mut errors,
+        );
+
+        let mut variant_options = VariantStringOptions::from_attrs(&variant.attrs, &mut errors);
+        variant
+            .attrs
+            .retain(|attr| !crate::helpers::is_enum_string_attr(attr));
+
+        if !options.skip_serde_impls {
+            variant_options.apply_serde_attrs(&variant.attrs, &mut errors);
+            variant
+                .attrs
+                .retain(|attr| !crate::helpers::is_serde_attr(attr));
+        }
+
+        // Skipped variants may carry anything, e.g. a `PhantomData` for the enum's generics
+        if variant_options.skip {
+            // A skipped variant has no string for `Display` or `as_str` to return
+            for (enabled, option) in &[
+                (options.impl_display, "display"),
+                (options.impl_as_str, "as_str"),
+            ] {
+                if *enabled {
+                    errors.error_spanned(
+                        &variant.ident,
+                        format!(
+                            "'{}' cannot be used with skipped variant {}::{}, as it has no string",
+                            option, enum_name, variant_name
+                        ),
+                    );
+                }
             }
-        })
-        .map(|v| {
-            if let Some(transform) = &options.transform {
-                (v.0, transform.transform(v.1))
-            } else {
-                v
+
+            // `repr` casts variants to integers, which is only possible when none have fields
+            if options.repr.is_some() && variant.fields != syn::Fields::Unit {
+                errors.error_spanned(
+                    &variant.fields,
+                    format!(
+                        "'repr' requires every variant to be a unit variant, but skipped variant {}::{} has fields",
+                        enum_name, variant_name
+                    ),
+                );
             }
-        })
-        .collect();
 
-    let mut enum_serialize_mappings = variants
-        .clone()
-        .into_iter()
-        .map(|(variant_ident, s)| {
-            quote! {
-                 #enum_ident::#variant_ident => #s
+            skipped_variants.push(SkippedVariant {
+                ident: variant.ident.clone(),
+                cfgs,
+            });
+            continue;
+        }
+
+        // The fallback arms aren't tied to a variant, so they can't be made conditional
+        if (variant_options.other || variant_options.default) && !cfgs.is_empty() {
+            errors.error_spanned(
+                &variant.ident,
+                format!(
+                    "The fallback variant {}::{} cannot be conditionally compiled",
+                    enum_name, variant_name
+                ),
+            );
+        }
+
+        if variant_options.other {
+            if !crate::helpers::is_newtype_variant(variant) {
+                errors.error_spanned(
+                    &variant.ident,
+                    format!(
+                        "#[enum_string(other)] variant {}::{} must have exactly one unnamed field, e.g. {}(String)",
+                        enum_name, variant_name, variant_name
+                    ),
+                );
             }
-        })
-        .collect::<Vec<TokenStream2>>();
 
-    enum_serialize_mappings.push(quote! { _ => unreachable!() });
+            if variant_options.rename.is_some() || !variant_options.aliases.is_empty() {
+                errors.error_spanned(
+                    &variant.ident,
+                    format!(
+                        "#[enum_string(other)] variant {}::{} cannot also be renamed or aliased",
+                        enum_name, variant_name
+                    ),
+                );
+            }
 
-    let serde_ser_impl = quote! {
-        impl serde::Serialize for #enum_ident {
-            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-            where
-                S: serde::Serializer
-            {
-                let ser_val = match *self {
-                    #(#enum_serialize_mappings),*
-                };
+            if let Some(existing) = other_variant.as_ref().or(default_variant.as_ref()) {
+                errors.error_spanned(
+                    &variant.ident,
+                    format!(
+                        "Only one variant of {} may be marked #[enum_string(other)] or #[enum_string(default)], found {} and {}",
+                        enum_name, existing, variant_name
+                    ),
+                );
+            }
+
+            if options.repr.is_some() || options.compact || options.unit_variant {
+                errors.error_spanned(
+                    &variant.ident,
+                    format!(
+                        "#[enum_string(other)] variant {}::{} cannot be used with 'repr', 'compact' or 'unit_variant'",
+                        enum_name, variant_name
+                    ),
+                );
+            }
+
+            other_variant = Some(variant.ident.clone());
+            continue;
+        }
+
+        if variant.fields != syn::Fields::Unit {
+            errors.error_spanned(
+                &variant.fields,
+                format!(
+                    "#[{}] only supports unit variants, but {}::{} has fields. Mark it #[enum_string(other)] to use it as a catch-all.",
+                    macro_name, enum_name, variant_name
+                ),
+            );
+            continue;
+        }
 
-                serializer.serialize_str(&*ser_val)
+        if variant_options.default {
+            if let Some(existing) = other_variant.as_ref().or(default_variant.as_ref()) {
+                errors.error_spanned(
+                    &variant.ident,
+                    format!(
+                        "Only one variant of {} may be marked #[enum_string(other)] or #[enum_string(default)], found {} and {}",
+                        enum_name, existing, variant_name
+                    ),
+                );
             }
+
+            default_variant = Some(variant.ident.clone());
+        }
+
+        // An explicit rename is used verbatim, bypassing the prefix and transform
+        let name = if let Some(rename) = variant_options.rename {
+            rename
+        } else {
+            options.variant_string(&enum_name, &variant_name)
+        };
+
+        variants.push(EnumStringVariant {
+            ident: variant.ident.clone(),
+            name,
+            aliases: variant_options.aliases,
+            cfgs,
+        });
+    }
+
+    if let Some(on_unknown) = &options.on_unknown {
+        if other_variant.is_none() && default_variant.is_none() {
+            errors.error_spanned(
+                on_unknown,
+                format!(
+                    "'on_unknown' in #[{}] requires a variant of {} marked #[enum_string(other)] or #[enum_string(default)]",
+                    macro_name, enum_name
+                ),
+            );
         }
+    }
+
+    // Reject any enum where two variants end up with the same string once prefixes, transforms,
+    // renames and aliases have been applied, or once normalised for matching, as only the first
+    // would ever be deserialized. Whether variants compiled under different conditions collide
+    // depends on the configuration, so for those the error is itself made conditional on both.
+    let mut string_owners: std::collections::HashMap<String, Vec<(&EnumStringVariant, &str)>> =
+        std::collections::HashMap::new();
+    let mut conditional_collisions: Vec<TokenStream2> = Vec::new();
+    let cfg_strings = |variant: &EnumStringVariant| {
+        variant
+            .cfgs
+            .iter()
+            .map(|attr| quote! { #attr }.to_string())
+            .collect::<Vec<String>>()
     };
 
-    let mut de_visitor_name = enum_name.clone();
-    de_visitor_name.push_str("StrVisitor");
-    let de_visitor_ident = syn::Ident::new(&*de_visitor_name, enum_ident.span());
+    for variant in &variants {
+        for candidate in std::iter::once(&variant.name).chain(&variant.aliases) {
+            let normalized = options.matching.normalize(candidate);
+            let owners = string_owners.entry(normalized.clone()).or_default();
 
-    let enum_deserialize_expecting = variants
-        .clone()
-        .into_iter()
-        .map(|(_, s)| format!("\"{}\"", &*s))
-        .collect::<Vec<String>>()
-        .join(", ");
-
-    let mut enum_deserialize_mappings = variants
-        .into_iter()
-        .map(|(variant_ident, s)| {
-            quote! {
-                 #s => Ok(#enum_ident::#variant_ident)
-            }
-        })
-        .collect::<Vec<TokenStream2>>();
+            for (owner, owner_string) in owners.iter() {
+                if owner.ident == variant.ident {
+                    continue;
+                }
 
-    enum_deserialize_mappings.push(quote! {
-        _ => Err(serde::de::Error::invalid_value(
-                        serde::de::Unexpected::Str(s),
-                        &self,
-             ))
-    });
+                let message = if options.matching.is_exact() {
+                    format!(
+                        "Variants {}::{} and {}::{} both use the string \"{}\"",
+                        enum_name, owner.ident, enum_name, variant.ident, candidate
+                    )
+                } else {
+                    format!(
+                        "Variants {}::{} (\"{}\") and {}::{} (\"{}\") both match \"{}\" once normalised for 'matching'",
+                        enum_name, owner.ident, owner_string, enum_name, variant.ident, candidate, normalized
+                    )
+                };
 
-    let serde_de_impl = quote! {
-        struct #de_visitor_ident;
+                if cfg_strings(owner) == cfg_strings(variant) {
+                    errors.error_spanned(&variant.ident, message);
+                } else {
+                    // Stacked `#[cfg]`s must all hold, so this only fires when both are enabled
+                    let owner_cfgs = &owner.cfgs;
+                    let cfgs = &variant.cfgs;
 
-        impl<'de> serde::Deserialize<'de> for #enum_ident {
-            fn deserialize<D>(deserializer: D) -> Result<#enum_ident, D::Error>
-            where
-                D: serde::Deserializer<'de>,
-            {
-                deserializer.deserialize_str(#de_visitor_ident)
+                    conditional_collisions.push(quote_spanned! {variant.ident.span()=>
+                        #(#owner_cfgs)*
+                        #(#cfgs)*
+                        ::core::compile_error! { #message }
+                    });
+                }
             }
+
+            owners.push((variant, candidate));
         }
+    }
 
-        impl<'de> serde::de::Visitor<'de> for #de_visitor_ident {
-            type Value = #enum_ident;
+    let item = if emit_item { Some(&item_input) } else { None };
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                formatter.write_str("one of: ")?;
-                formatter.write_str(#enum_deserialize_expecting)
-            }
+    // Everything past here assumes valid options, so bail out with the enum itself still
+    // emitted to avoid a cascade of unrelated errors where it's used
+    if !errors.is_empty() {
+        let compile_errors = errors.to_compile_errors();
 
-            fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                match s {
-                    #(#enum_deserialize_mappings),*
+        return quote! {
+            #item
+            #compile_errors
+        };
+    }
+
+    let mut enum_serialize_mappings = variants
+        .iter()
+        .map(
+            |EnumStringVariant {
+                 ident: variant_ident,
+                 name: s,
+                 cfgs,
+                 ..
+             }| {
+                quote! {
+                     #(#cfgs)*
+                     #enum_ident::#variant_ident => #s
                 }
+            },
+        )
+        .collect::<Vec<TokenStream2>>();
+
+    // Unknown values captured by the catch-all variant are written back verbatim
+    if let Some(other_ident) = &other_variant {
+        enum_serialize_mappings.push(quote! {
+            #enum_ident::#other_ident(ref value) => ::core::convert::AsRef::<str>::as_ref(value)
+        });
+    }
+
+    // Skipped variants have no string, so each user of the match decides what to do with them
+    let skipped_message = |variant_ident: &syn::Ident| {
+        format!(
+            "the enum variant {}::{} cannot be serialized",
+            enum_name, variant_ident
+        )
+    };
+    let ser_match = |subject: &TokenStream2,
+                     unrepresentable: &dyn Fn(&syn::Ident) -> TokenStream2| {
+        let enum_serialize_mappings = &enum_serialize_mappings;
+        let skipped_mappings = skipped_variants.iter().map(|skipped| {
+            let SkippedVariant {
+                ident: variant_ident,
+                cfgs,
+            } = skipped;
+            let unrepresentable = unrepresentable(variant_ident);
+            quote! {
+                #(#cfgs)*
+                #enum_ident::#variant_ident { .. } => #unrepresentable
+            }
+        });
+
+        quote! {
+            match #subject {
+                #(#skipped_mappings,)*
+                #(#enum_serialize_mappings),*
             }
         }
     };
+    let ser_error = |variant_ident: &syn::Ident| {
+        let message = skipped_message(variant_ident);
+        quote! {
+            return ::core::result::Result::Err(#serde::ser::Error::custom(#message))
+        }
+    };
+
+    // The index of each variant in declaration order, used by `compact` and `unit_variant`
+    let variant_indices = (0..variants.len() as u32)
+        .map(|index| quote! { #index })
+        .collect::<Vec<TokenStream2>>();
+
+    // The integer each variant is encoded as, if any: its discriminant as `repr`, or otherwise
+    // its index when `compact` is used
+    let int_encoding = if let Some(repr) = &options.repr {
+        let values = variants
+            .iter()
+            .map(|variant| {
+                let variant_ident = &variant.ident;
+                quote! { #enum_ident::#variant_ident as #repr }
+            })
+            .collect::<Vec<TokenStream2>>();
+
+        Some((repr.clone(), values))
+    } else if options.compact {
+        Some((
+            syn::Ident::new("u32", enum_ident.span()),
+            variant_indices.clone(),
+        ))
+    } else {
+        None
+    };
+
+    // The value being serialized is `self`, unless serialization is done by a `with` module
+    let ser_subject = if options.with_module.is_some() {
+        quote! { *value }
+    } else {
+        quote! { *self }
+    };
+
+    let str_ser_match = ser_match(&ser_subject, &ser_error);
+    let str_serialize_body = quote! {
+        let ser_val: &str = #str_ser_match;
+
+        serializer.serialize_str(&*ser_val)
+    };
 
-    let output = quote! {
-        #item_input
-        #serde_ser_impl
-        #serde_de_impl
+    let int_serialize_body = int_encoding.as_ref().map(|(int_type, values)| {
+        let serialize_fn = syn::Ident::new(&format!("serialize_{}", int_type), int_type.span());
+        let int_ser_match = int_ser_match(
+            &ser_subject,
+            &enum_ident,
+            &variants,
+            values,
+            &skipped_variants,
+            &ser_error,
+        );
+
+        quote! {
+            serializer.#serialize_fn(#int_ser_match)
+        }
+    });
+
+    // An `enum` without variants has no values, so there's nothing to serialize and code
+    // following the empty match would be unreachable
+    let uninhabited = item_input.variants.is_empty();
+
+    // The attribute macro sees `#[cfg(...)]`s before they're evaluated, so if every variant is
+    // conditional the `enum` may turn out to be empty anyway
+    let allow_unreachable = if !uninhabited
+        && item_input
+            .variants
+            .iter()
+            .all(|variant| !crate::helpers::cfg_attrs(&variant.attrs).is_empty())
+    {
+        quote! { #[allow(unreachable_code)] }
+    } else {
+        quote! {}
     };
+    let serialize_body = match (int_serialize_body, &options.dual_input) {
+        _ if uninhabited => quote! { match #ser_subject {} },
+        _ if options.unit_variant => {
+            let index_ser_match = int_ser_match(
+                &ser_subject,
+                &enum_ident,
+                &variants,
+                &variant_indices,
+                &skipped_variants,
+                &ser_error,
+            );
+
+            quote! {
+                let variant_index: u32 = #index_ser_match;
+                let variant: &'static str = #str_ser_match;
 
-    output.into()
+                serializer.serialize_unit_variant(#enum_name, variant_index, variant)
+            }
+        }
+        (Some(int_serialize_body), _) if options.compact => quote! {
+            if serializer.is_human_readable() {
+                #str_serialize_body
+            } else {
+                #int_serialize_body
+            }
+        },
+        (Some(_), Some(DualInputCanonical::String)) | (None, _) => str_serialize_body,
+        (Some(int_serialize_body), _) => int_serialize_body,
+    };
+
+    let mut de_visitor_name = enum_name.clone();
+    de_visitor_name.push_str("StrVisitor");
+    let de_visitor_ident = syn::Ident::new(&de_visitor_name, enum_ident.span());
+    let de_visitor = quote! { #de_visitor_ident(::core::marker::PhantomData) };
+
+    // Aliases are accepted but deliberately left out of the expected values. The list is
+    // built at runtime so that it only includes variants which are compiled in.
+    let expected_names = variants.iter().map(|variant| {
+        let cfgs = &variant.cfgs;
+        let quoted_name = format!("\"{}\"", variant.name);
+
+        quote! {
+            #(#cfgs)*
+            #quoted_name
+        }
+    });
+    let no_expected_values = format!("nothing, as {} has no variants", enum_name);
+    let str_expecting = quote! {
+        let names: &[&str] = &[#(#expected_names),*];
+
+        if names.is_empty() {
+            return formatter.write_str(#no_expected_values);
+        }
+
+        formatter.write_str("one of: ")?;
+
+        for (i, name) in names.iter().enumerate() {
+            if i > 0 {
+                formatter.write_str(", ")?;
+            }
+
+            formatter.write_str(name)?;
+        }
+    };
+
+    // Input is normalised before matching, so the patterns must be normalised the same way
+    let enum_deserialize_mappings = variants
+        .iter()
+        .map(
+            |EnumStringVariant {
+                 ident: variant_ident,
+                 name,
+                 aliases,
+                 cfgs,
+             }| {
+                let mut patterns: Vec<String> = Vec::new();
+
+                for candidate in std::iter::once(name).chain(aliases) {
+                    let normalized = options.matching.normalize(candidate);
+
+                    if !patterns.contains(&normalized) {
+                        patterns.push(normalized);
+                    }
+                }
+
+                quote! {
+                     #(#cfgs)*
+                     #(#patterns)|* => ::core::result::Result::Ok(#enum_ident::#variant_ident)
+                }
+            },
+        )
+        .collect::<Vec<TokenStream2>>();
+
+    // Give the user a chance to observe values that are being swallowed by a fallback
+    let on_unknown_call = options.on_unknown.as_ref().map(|hook| {
+        quote! { #hook(s); }
+    });
+    let on_unknown_int_call = options.on_unknown.as_ref().map(|hook| {
+        quote! { #hook(&v.to_string()); }
+    });
+
+    let fallback_value = match (&other_variant, &default_variant) {
+        (Some(other_ident), _) => Some(quote! { #enum_ident::#other_ident(s.to_owned().into()) }),
+        (None, Some(default_ident)) => Some(quote! { #enum_ident::#default_ident }),
+        (None, None) => None,
+    };
+
+    let fallback_mapping = fallback_value.map(|fallback_value| {
+        quote! {
+            _ => {
+                #on_unknown_call
+                ::core::result::Result::Ok(#fallback_value)
+            }
+        }
+    });
+
+    let match_key = options.matching.normalize_tokens(quote! { s });
+
+    // Build the match on the (normalised) input string, using `invalid` to report failures
+    // when there's no fallback variant to catch unknown values
+    let de_match = |invalid: TokenStream2| {
+        let enum_deserialize_mappings = &enum_deserialize_mappings;
+        let fallback_mapping = fallback_mapping.clone().unwrap_or_else(|| {
+            quote! {
+                _ => ::core::result::Result::Err(#invalid)
+            }
+        });
+
+        quote! {
+            let key = #match_key;
+
+            match &*key {
+                #(#enum_deserialize_mappings,)*
+                #fallback_mapping
+            }
+        }
+    };
+
+    let visit_str_match = if options.suggest {
+        let suggestions = suggestions(&serde, &variants);
+
+        de_match(quote! {{
+            #suggestions
+
+            #serde::de::Error::invalid_value(
+                #serde::de::Unexpected::Str(s),
+                &__Suggesting {
+                    expected: &self,
+                    input: s,
+                },
+            )
+        }})
+    } else {
+        de_match(quote! {
+            #serde::de::Error::invalid_value(#serde::de::Unexpected::Str(s), &self)
+        })
+    };
+
+    // Owned strings are otherwise copied, which the catch-all variant can avoid by taking
+    // ownership of an unknown value. Without one, serde's default forwards to `visit_str`.
+    let visit_string_fn = other_variant.as_ref().map(|other_ident| {
+        let enum_deserialize_mappings = &enum_deserialize_mappings;
+        let on_unknown_call = options.on_unknown.as_ref().map(|hook| {
+            quote! { #hook(&s); }
+        });
+
+        quote! {
+            fn visit_string<__E>(
+                self,
+                s: ::std::string::String,
+            ) -> ::core::result::Result<Self::Value, __E>
+            where
+                __E: #serde::de::Error,
+            {
+                let known: ::core::result::Result<Self::Value, ()> = {
+                    let s = &*s;
+                    let key = #match_key;
+
+                    match &*key {
+                        #(#enum_deserialize_mappings,)*
+                        _ => ::core::result::Result::Err(()),
+                    }
+                };
+
+                match known {
+                    ::core::result::Result::Ok(value) => ::core::result::Result::Ok(value),
+                    ::core::result::Result::Err(()) => {
+                        #on_unknown_call
+                        ::core::result::Result::Ok(#enum_ident::#other_ident(s.into()))
+                    }
+                }
+            }
+        }
+    });
+
+    // Some formats hand strings over as borrowed data, or as raw bytes (e.g. MessagePack's
+    // `bin` or CBOR byte strings), which are accepted if they're valid UTF-8
+    let visit_str_fn = quote! {
+        fn visit_str<__E>(self, s: &str) -> ::core::result::Result<Self::Value, __E>
+        where
+            __E: #serde::de::Error,
+        {
+            #visit_str_match
+        }
+
+        fn visit_borrowed_str<__E>(self, s: &'de str) -> ::core::result::Result<Self::Value, __E>
+        where
+            __E: #serde::de::Error,
+        {
+            self.visit_str(s)
+        }
+
+        #visit_string_fn
+
+        fn visit_bytes<__E>(self, v: &[u8]) -> ::core::result::Result<Self::Value, __E>
+        where
+            __E: #serde::de::Error,
+        {
+            match ::core::str::from_utf8(v) {
+                ::core::result::Result::Ok(s) => self.visit_str(s),
+                ::core::result::Result::Err(_) => ::core::result::Result::Err(
+                    #serde::de::Error::invalid_value(#serde::de::Unexpected::Bytes(v), &self),
+                ),
+            }
+        }
+    };
+
+    let int_deserialize = int_encoding.as_ref().map(|(int_type, values)| {
+        let int_fallback_value = default_variant
+            .as_ref()
+            .map(|default_ident| quote! { #enum_ident::#default_ident });
+        let visit_int_fns = visit_int_fns(
+            &serde,
+            &enum_ident,
+            int_type,
+            &variants,
+            values,
+            int_fallback_value,
+            on_unknown_int_call.clone(),
+        );
+        let expected_values = variants.iter().zip(values).map(|(variant, value)| {
+            let cfgs = &variant.cfgs;
+
+            // Parenthesised, as attributes are only allowed on simple expressions
+            quote! {
+                #(#cfgs)*
+                (#value)
+            }
+        });
+        let int_expecting = quote! {
+            let values: &[#int_type] = &[#(#expected_values),*];
+
+            if values.is_empty() {
+                return formatter.write_str(#no_expected_values);
+            }
+
+            formatter.write_str("one of: ")?;
+
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    formatter.write_str(", ")?;
+                }
+
+                ::core::write!(formatter, "{}", value)?;
+            }
+        };
+        // Read the integer at the width it was written, as non-self-describing formats such as
+        // bincode depend on it. serde forwards the narrower `visit_*` methods to the wider ones.
+        let deserialize_call =
+            syn::Ident::new(&format!("deserialize_{}", int_type), int_type.span());
+
+        (deserialize_call, int_expecting, visit_int_fns)
+    });
+
+    let (deserialize_body, expecting_body, visitor_fns) = match int_deserialize {
+        // Formats identify the variant by name or by index, which the visitor handles as for
+        // plain strings and `compact`
+        _ if options.unit_variant => {
+            let variant_names = variants.iter().map(|variant| {
+                let name = &variant.name;
+                let cfgs = &variant.cfgs;
+                quote! { #(#cfgs)* #name }
+            });
+            let visit_index_fns = visit_int_fns(
+                &serde,
+                &enum_ident,
+                &syn::Ident::new("u32", enum_ident.span()),
+                &variants,
+                &variant_indices,
+                default_variant
+                    .as_ref()
+                    .map(|default_ident| quote! { #enum_ident::#default_ident }),
+                on_unknown_int_call,
+            );
+
+            (
+                quote! {
+                    const VARIANTS: &[&str] = &[#(#variant_names),*];
+
+                    deserializer.deserialize_enum(#enum_name, VARIANTS, #de_visitor)
+                },
+                str_expecting.clone(),
+                quote! {
+                    #visit_str_fn
+                    #visit_index_fns
+
+                    fn visit_enum<__A>(self, data: __A) -> ::core::result::Result<Self::Value, __A::Error>
+                    where
+                        __A: #serde::de::EnumAccess<'de>,
+                    {
+                        let (value, variant) = #serde::de::EnumAccess::variant_seed(data, self)?;
+                        #serde::de::VariantAccess::unit_variant(variant)?;
+
+                        ::core::result::Result::Ok(value)
+                    }
+                },
+            )
+        }
+        Some((int_deserialize_call, int_expecting, visit_int_fns)) if options.compact => {
+            // `compact` picks strings or integers based on the format, and tells the visitor
+            // which so that errors only list the values the format could have given
+            (
+                quote! {
+                    if deserializer.is_human_readable() {
+                        deserializer.deserialize_str(
+                            #de_visitor_ident(::core::marker::PhantomData, true),
+                        )
+                    } else {
+                        deserializer.#int_deserialize_call(
+                            #de_visitor_ident(::core::marker::PhantomData, false),
+                        )
+                    }
+                },
+                quote! {
+                    if self.1 {
+                        #str_expecting
+                    } else {
+                        #int_expecting
+                    }
+                },
+                quote! {
+                    #visit_str_fn
+                    #visit_int_fns
+                },
+            )
+        }
+        Some((_, int_expecting, visit_int_fns)) if options.dual_input.is_some() => (
+            // The format has to tell us whether it has a string or an integer
+            quote! { deserializer.deserialize_any(#de_visitor) },
+            quote! {
+                #str_expecting
+                formatter.write_str(", or ")?;
+                #int_expecting
+            },
+            quote! {
+                #visit_str_fn
+                #visit_int_fns
+            },
+        ),
+        Some((int_deserialize_call, int_expecting, visit_int_fns)) => (
+            quote! { deserializer.#int_deserialize_call(#de_visitor) },
+            int_expecting,
+            visit_int_fns,
+        ),
+        None => (
+            quote! { deserializer.deserialize_str(#de_visitor) },
+            str_expecting.clone(),
+            visit_str_fn,
+        ),
+    };
+
+    // The visitor carries the enum's generics, so it needs them in its type
+    let de_visitor_generics = &item_input.generics;
+    let mut de_generics = item_input.generics.clone();
+    de_generics.params.insert(0, syn::parse_quote! { 'de });
+    let (de_impl_generics, _, _) = de_generics.split_for_impl();
+
+    // With `unit_variant`, the visitor also deserializes the variant's identifier
+    let de_seed_impl = if options.unit_variant {
+        quote! {
+            impl #de_impl_generics #serde::de::DeserializeSeed<'de> for #de_visitor_ident #ty_generics #where_clause {
+                type Value = #enum_ident #ty_generics;
+
+                fn deserialize<__D>(self, deserializer: __D) -> ::core::result::Result<Self::Value, __D::Error>
+                where
+                    __D: #serde::Deserializer<'de>,
+                {
+                    deserializer.deserialize_identifier(self)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // With `compact`, the visitor also records whether the format is human readable
+    let de_visitor_human_readable = if options.compact {
+        quote! { , bool }
+    } else {
+        quote! {}
+    };
+
+    let de_visitor_decl = quote! {
+        struct #de_visitor_ident #de_visitor_generics (
+            ::core::marker::PhantomData<fn() -> #enum_ident #ty_generics>
+            #de_visitor_human_readable
+        ) #where_clause;
+
+        impl #de_impl_generics #serde::de::Visitor<'de> for #de_visitor_ident #ty_generics #where_clause {
+            type Value = #enum_ident #ty_generics;
+
+            fn expecting(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                #expecting_body
+
+                ::core::result::Result::Ok(())
+            }
+
+            #visitor_fns
+        }
+
+        #de_seed_impl
+    };
+
+    // A `with` module holds the serialization logic itself, so that it still works when the
+    // enum has no impls of its own, and the impls (if any) call into it
+    let (serialize_body, deserialize_body, with_module) = match &options.with_module {
+        Some(module_ident) => {
+            let vis = &item_input.vis;
+            let companion_modules = with_companion_modules(&serde, &enum_ident);
+
+            let with_module = quote! {
+                #[allow(dead_code)]
+                #vis mod #module_ident {
+                    use super::*;
+
+                    #de_visitor_decl
+
+                    #allow_unreachable
+                    pub fn serialize<__S>(
+                        value: &#enum_ident,
+                        serializer: __S,
+                    ) -> ::core::result::Result<__S::Ok, __S::Error>
+                    where
+                        __S: #serde::Serializer
+                    {
+                        #serialize_body
+                    }
+
+                    pub fn deserialize<'de, __D>(
+                        deserializer: __D,
+                    ) -> ::core::result::Result<#enum_ident, __D::Error>
+                    where
+                        __D: #serde::Deserializer<'de>,
+                    {
+                        #deserialize_body
+                    }
+
+                    #companion_modules
+                }
+            };
+
+            (
+                quote! { #module_ident::serialize(self, serializer) },
+                quote! { #module_ident::deserialize(deserializer) },
+                with_module,
+            )
+        }
+        None => (
+            serialize_body,
+            quote! {
+                #de_visitor_decl
+
+                #deserialize_body
+            },
+            quote! {},
+        ),
+    };
+
+    let serde_impls = if options.skip_serde_impls {
+        quote! {}
+    } else {
+        quote! {
+            impl #impl_generics #serde::Serialize for #enum_ident #ty_generics #where_clause {
+                #allow_unreachable
+                fn serialize<__S>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+                where
+                    __S: #serde::Serializer
+                {
+                    #serialize_body
+                }
+            }
+
+            impl #de_impl_generics #serde::Deserialize<'de> for #enum_ident #ty_generics #where_clause {
+                fn deserialize<__D>(deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+                where
+                    __D: #serde::Deserializer<'de>,
+                {
+                    #deserialize_body
+                }
+            }
+        }
+    };
+
+    let display_impl = if options.impl_display {
+        let display_match = ser_match(&quote! { *self }, &|_| {
+            unreachable!("'display' is rejected when variants are skipped")
+        });
+        let display_body = if uninhabited {
+            quote! { match *self {} }
+        } else {
+            quote! { formatter.write_str(#display_match) }
+        };
+
+        quote! {
+            impl #impl_generics ::core::fmt::Display for #enum_ident #ty_generics #where_clause {
+                #allow_unreachable
+                fn fmt(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    #display_body
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // The catch-all variant borrows its string from `self`, so it can't hand out a `'static` one
+    let as_str_impl = if options.impl_as_str {
+        let as_str_lifetime = if other_variant.is_some() {
+            quote! {}
+        } else {
+            quote! { 'static }
+        };
+
+        let as_str_match = if uninhabited {
+            quote! { match *self {} }
+        } else {
+            ser_match(&quote! { *self }, &|_| {
+                unreachable!("'as_str' is rejected when variants are skipped")
+            })
+        };
+
+        quote! {
+            impl #impl_generics #enum_ident #ty_generics #where_clause {
+                /// Returns the string this variant is serialized as.
+                #allow_unreachable
+                pub fn as_str(&self) -> &#as_str_lifetime str {
+                    #as_str_match
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // The parse error has to be nameable by users, so it's declared outside of the scope
+    // wrapping the rest of the generated code
+    let (parse_error_decl, from_str_impl) = if options.impl_from_str {
+        let vis = &item_input.vis;
+        let parse_error_ident =
+            syn::Ident::new(&format!("Parse{}Error", enum_name), enum_ident.span());
+        let parse_error_doc = format!(
+            "The error returned when parsing a [`{}`] from a string fails.",
+            enum_name
+        );
+        let from_str_match = de_match(quote! {
+            #parse_error_ident { value: s.to_owned() }
+        });
+
+        let parse_error_decl = quote! {
+            #[doc = #parse_error_doc]
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #vis struct #parse_error_ident {
+                value: ::std::string::String,
+            }
+        };
+
+        let from_str_impl = quote! {
+            impl #parse_error_ident {
+                /// Returns the string which failed to parse.
+                pub fn value(&self) -> &str {
+                    &self.value
+                }
+            }
+
+            impl ::core::fmt::Display for #parse_error_ident {
+                fn fmt(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::write!(
+                        formatter,
+                        "invalid value \"{}\", expected ",
+                        self.value
+                    )?;
+                    #str_expecting
+
+                    ::core::result::Result::Ok(())
+                }
+            }
+
+            impl ::std::error::Error for #parse_error_ident {}
+
+            impl #impl_generics ::core::str::FromStr for #enum_ident #ty_generics #where_clause {
+                type Err = #parse_error_ident;
+
+                fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                    #from_str_match
+                }
+            }
+        };
+
+        (parse_error_decl, from_str_impl)
+    } else {
+        (quote! {}, quote! {})
+    };
+
+    // The catch-all and skipped variants may carry data, so they aren't part of the listed
+    // variants. The list is only available when the enum's generics allow a `'static` slice.
+    let variants_impl = if options.impl_variants {
+        let variant_paths = variants.iter().map(|variant| {
+            let variant_ident = &variant.ident;
+            let cfgs = &variant.cfgs;
+            quote! { #(#cfgs)* #enum_ident::#variant_ident }
+        });
+        let variant_names = variants.iter().map(|variant| {
+            let name = &variant.name;
+            let cfgs = &variant.cfgs;
+            quote! { #(#cfgs)* #name }
+        });
+
+        let mut variants_where_clause = generics.clone().make_where_clause().clone();
+        variants_where_clause
+            .predicates
+            .push(syn::parse_quote! { Self: 'static });
+
+        quote! {
+            impl #impl_generics #enum_ident #ty_generics #variants_where_clause {
+                /// All variants, in declaration order.
+                pub const VARIANTS: &'static [Self] = &[#(#variant_paths),*];
+
+                /// The serialized string of each variant, in the same order as `VARIANTS`.
+                pub const NAMES: &'static [&'static str] = &[#(#variant_names),*];
+
+                /// Iterates over all variants, in declaration order.
+                pub fn iter() -> ::core::slice::Iter<'static, Self> {
+                    Self::VARIANTS.iter()
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let discriminant_checks = options
+        .repr
+        .as_ref()
+        .map(|repr| discriminant_checks(&enum_ident, repr, &variants));
+
+    // Everything except the enum and parse error is wrapped in an anonymous scope, so the
+    // visitor and other helpers can't clash with anything in the user's module
+    quote! {
+        #item
+        #parse_error_decl
+        #with_module
+
+        const _: () = {
+            #(#conditional_collisions)*
+            #discriminant_checks
+            #serde_impls
+            #display_impl
+            #as_str_impl
+            #from_str_impl
+            #variants_impl
+        };
+    }
+}
+
+/// Generate the `option`, `vec` and `map_key` modules nested inside a `with` module, which
+/// adapt its `serialize` and `deserialize` functions to `Option`s, `Vec`s and map keys.
+fn with_companion_modules(serde: &syn::Path, enum_ident: &syn::Ident) -> TokenStream2 {
+    let wrappers = quote! {
+        struct __Ser<'a>(&'a #enum_ident);
+
+        impl<'a> #serde::Serialize for __Ser<'a> {
+            fn serialize<__S>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+            where
+                __S: #serde::Serializer
+            {
+                serialize(self.0, serializer)
+            }
+        }
+
+        struct __De(#enum_ident);
+
+        impl<'de> #serde::Deserialize<'de> for __De {
+            fn deserialize<__D>(deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+            where
+                __D: #serde::Deserializer<'de>,
+            {
+                deserialize(deserializer).map(__De)
+            }
+        }
+    };
+
+    let option_module = quote! {
+        pub mod option {
+            use super::*;
+
+            pub fn serialize<__S>(
+                value: &::core::option::Option<#enum_ident>,
+                serializer: __S,
+            ) -> ::core::result::Result<__S::Ok, __S::Error>
+            where
+                __S: #serde::Serializer
+            {
+                #serde::Serialize::serialize(&value.as_ref().map(__Ser), serializer)
+            }
+
+            pub fn deserialize<'de, __D>(
+                deserializer: __D,
+            ) -> ::core::result::Result<::core::option::Option<#enum_ident>, __D::Error>
+            where
+                __D: #serde::Deserializer<'de>,
+            {
+                <::core::option::Option<__De> as #serde::Deserialize>::deserialize(deserializer)
+                    .map(|value| value.map(|__De(value)| value))
+            }
+        }
+    };
+
+    let vec_module = quote! {
+        pub mod vec {
+            use super::*;
+
+            pub fn serialize<__S>(
+                value: &[#enum_ident],
+                serializer: __S,
+            ) -> ::core::result::Result<__S::Ok, __S::Error>
+            where
+                __S: #serde::Serializer
+            {
+                #serde::Serializer::collect_seq(serializer, value.iter().map(__Ser))
+            }
+
+            pub fn deserialize<'de, __D>(
+                deserializer: __D,
+            ) -> ::core::result::Result<::std::vec::Vec<#enum_ident>, __D::Error>
+            where
+                __D: #serde::Deserializer<'de>,
+            {
+                <::std::vec::Vec<__De> as #serde::Deserialize>::deserialize(deserializer)
+                    .map(|values| values.into_iter().map(|__De(value)| value).collect())
+            }
+        }
+    };
+
+    let map_visitor = quote! {
+        struct __MapVisitor<__M, __V>(::core::marker::PhantomData<fn() -> (__M, __V)>);
+
+        impl<'de, __M, __V> #serde::de::Visitor<'de> for __MapVisitor<__M, __V>
+        where
+            __M: ::core::iter::FromIterator<(#enum_ident, __V)>,
+            __V: #serde::Deserialize<'de>,
+        {
+            type Value = __M;
+
+            fn expecting(
+                &self,
+                formatter: &mut ::core::fmt::Formatter,
+            ) -> ::core::fmt::Result {
+                formatter.write_str("a map")
+            }
+
+            fn visit_map<__A>(self, mut map: __A) -> ::core::result::Result<__M, __A::Error>
+            where
+                __A: #serde::de::MapAccess<'de>,
+            {
+                let mut entries = ::std::vec::Vec::new();
+
+                while let ::core::option::Option::Some((__De(key), value)) =
+                    #serde::de::MapAccess::next_entry::<__De, __V>(&mut map)?
+                {
+                    entries.push((key, value));
+                }
+
+                ::core::result::Result::Ok(entries.into_iter().collect())
+            }
+        }
+    };
+
+    let map_key_module = quote! {
+        pub mod map_key {
+            use super::*;
+
+            pub fn serialize<'a, __M, __V, __S>(
+                value: &'a __M,
+                serializer: __S,
+            ) -> ::core::result::Result<__S::Ok, __S::Error>
+            where
+                &'a __M: ::core::iter::IntoIterator<Item = (&'a #enum_ident, &'a __V)>,
+                __V: #serde::Serialize + 'a,
+                __S: #serde::Serializer
+            {
+                #serde::Serializer::collect_map(
+                    serializer,
+                    value.into_iter().map(|(key, value)| (__Ser(key), value)),
+                )
+            }
+
+            pub fn deserialize<'de, __M, __V, __D>(
+                deserializer: __D,
+            ) -> ::core::result::Result<__M, __D::Error>
+            where
+                __M: ::core::iter::FromIterator<(#enum_ident, __V)>,
+                __V: #serde::Deserialize<'de>,
+                __D: #serde::Deserializer<'de>,
+            {
+                #serde::Deserializer::deserialize_map(
+                    deserializer,
+                    __MapVisitor(::core::marker::PhantomData),
+                )
+            }
+
+            #map_visitor
+        }
+    };
+
+    quote! {
+        #wrappers
+        #option_module
+        #vec_module
+        #map_key_module
+    }
+}
+
+/// Generate a `match` on `subject` evaluating to the integer value of each variant.
+fn int_ser_match(
+    subject: &TokenStream2,
+    enum_ident: &syn::Ident,
+    variants: &[EnumStringVariant],
+    values: &[TokenStream2],
+    skipped_variants: &[SkippedVariant],
+    unrepresentable: &dyn Fn(&syn::Ident) -> TokenStream2,
+) -> TokenStream2 {
+    let mappings = variants.iter().zip(values).map(|(variant, value)| {
+        let variant_ident = &variant.ident;
+        let cfgs = &variant.cfgs;
+
+        quote! {
+            #(#cfgs)*
+            #enum_ident::#variant_ident => #value
+        }
+    });
+    let skipped_mappings = skipped_variants.iter().map(|skipped| {
+        let variant_ident = &skipped.ident;
+        let cfgs = &skipped.cfgs;
+        let unrepresentable = unrepresentable(variant_ident);
+        quote! {
+            #(#cfgs)*
+            #enum_ident::#variant_ident { .. } => #unrepresentable
+        }
+    });
+
+    quote! {
+        match #subject {
+            #(#skipped_mappings,)*
+            #(#mappings,)*
+        }
+    }
+}
+
+/// Generate a `__Suggesting` wrapper for the expected values of a visitor, which appends the
+/// names of `variants` closest to the unrecognised input to the message. It's declared where
+/// it's used, as the visitor may live in a `with` module outside of the scope of everything else.
+fn suggestions(serde: &syn::Path, variants: &[EnumStringVariant]) -> TokenStream2 {
+    let names = variants.iter().map(|variant| {
+        let name = &variant.name;
+        let cfgs = &variant.cfgs;
+        quote! { #(#cfgs)* #name }
+    });
+
+    quote! {
+        struct __Suggesting<'a> {
+            expected: &'a dyn #serde::de::Expected,
+            input: &'a str,
+        }
+
+        impl<'a> #serde::de::Expected for __Suggesting<'a> {
+            fn fmt(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                fn edit_distance(a: &str, b: &str) -> usize {
+                    let b: ::std::vec::Vec<char> = b.chars().collect();
+                    let mut row: ::std::vec::Vec<usize> = (0..=b.len()).collect();
+
+                    for (i, a_char) in a.chars().enumerate() {
+                        let mut diagonal = row[0];
+                        row[0] = i + 1;
+
+                        for (j, b_char) in b.iter().enumerate() {
+                            let above = row[j + 1];
+                            row[j + 1] = if a_char == *b_char {
+                                diagonal
+                            } else {
+                                1 + diagonal.min(above).min(row[j])
+                            };
+                            diagonal = above;
+                        }
+                    }
+
+                    row[b.len()]
+                }
+
+                let names: &[&str] = &[#(#names),*];
+                let input = self.input.to_lowercase();
+                let threshold = (self.input.chars().count() / 3).max(2);
+                let distances = names
+                    .iter()
+                    .map(|name| edit_distance(&input, &name.to_lowercase()))
+                    .collect::<::std::vec::Vec<usize>>();
+
+                self.expected.fmt(formatter)?;
+
+                let closest = match distances.iter().min() {
+                    ::core::option::Option::Some(&closest) if closest <= threshold => closest,
+                    _ => return ::core::result::Result::Ok(()),
+                };
+                let suggestions = names
+                    .iter()
+                    .zip(&distances)
+                    .filter(|(_, distance)| **distance == closest)
+                    .map(|(name, _)| name)
+                    .collect::<::std::vec::Vec<&&str>>();
+
+                formatter.write_str("; did you mean ")?;
+
+                for (i, suggestion) in suggestions.iter().enumerate() {
+                    if i > 0 {
+                        let separator = if i + 1 == suggestions.len() { " or " } else { ", " };
+                        formatter.write_str(separator)?;
+                    }
+
+                    ::core::write!(formatter, "\"{}\"", suggestion)?;
+                }
+
+                formatter.write_str("?")
+            }
+        }
+    }
+}
+
+/// Generate compile time assertions that the discriminant of each variant fits in `repr`.
+fn discriminant_checks(
+    enum_ident: &syn::Ident,
+    repr: &syn::Ident,
+    variants: &[EnumStringVariant],
+) -> TokenStream2 {
+    let checks = variants.iter().map(|variant| {
+        let variant_ident = &variant.ident;
+        let cfgs = &variant.cfgs;
+        let overflow_message = format!(
+            "discriminant of {}::{} does not fit in {}",
+            enum_ident, variant_ident, repr
+        );
+
+        quote! {
+            #(#cfgs)*
+            const _: () = ::core::assert!(
+                #enum_ident::#variant_ident as i128 == #enum_ident::#variant_ident as #repr as i128,
+                #overflow_message
+            );
+        }
+    });
+
+    quote! {
+        #(#checks)*
+    }
+}
+
+/// Generate `visit_u64` and `visit_i64` for a visitor matching integers against the
+/// integer `values` of `variants`. Values out of range for `int_type` are treated as unknown.
+fn visit_int_fns(
+    serde: &syn::Path,
+    enum_ident: &syn::Ident,
+    int_type: &syn::Ident,
+    variants: &[EnumStringVariant],
+    values: &[TokenStream2],
+    fallback_value: Option<TokenStream2>,
+    on_unknown_call: Option<TokenStream2>,
+) -> TokenStream2 {
+    let visit_int_fn = |visit_fn: TokenStream2,
+                        visit_type: TokenStream2,
+                        unexpected: TokenStream2| {
+        let mappings = variants.iter().zip(values).map(|(variant, value)| {
+                let variant_ident = &variant.ident;
+                let cfgs = &variant.cfgs;
+
+                quote! {
+                    #(#cfgs)*
+                    ::core::result::Result::Ok(key) if key == #value => ::core::result::Result::Ok(#enum_ident::#variant_ident)
+                }
+            });
+
+        let fallback_mapping = if let Some(fallback_value) = &fallback_value {
+            quote! {
+                _ => {
+                    #on_unknown_call
+                    ::core::result::Result::Ok(#fallback_value)
+                }
+            }
+        } else {
+            quote! {
+                _ => ::core::result::Result::Err(#serde::de::Error::invalid_value(
+                    #serde::de::Unexpected::#unexpected(v),
+                    &self,
+                ))
+            }
+        };
+
+        quote! {
+            fn #visit_fn<__E>(self, v: #visit_type) -> ::core::result::Result<Self::Value, __E>
+            where
+                __E: #serde::de::Error,
+            {
+                match <#int_type as ::core::convert::TryFrom<#visit_type>>::try_from(v) {
+                    #(#mappings,)*
+                    #fallback_mapping
+                }
+            }
+        }
+    };
+
+    let visit_u64 = visit_int_fn(quote! { visit_u64 }, quote! { u64 }, quote! { Unsigned });
+    let visit_i64 = visit_int_fn(quote! { visit_i64 }, quote! { i64 }, quote! { Signed });
+
+    quote! {
+        #visit_u64
+        #visit_i64
+    }
 }
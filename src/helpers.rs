@@ -1,62 +1,220 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0+
 
+use std::fmt::Display;
+
+use quote::ToTokens;
+use syn::export::TokenStream2;
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+
+/// Collects errors while processing macro input, so they can all be reported together
+/// rather than one per compile.
+#[derive(Default)]
+pub(crate) struct Errors {
+    errors: Vec<syn::Error>,
+}
+
+impl Errors {
+    pub(crate) fn push(&mut self, error: syn::Error) {
+        self.errors.push(error);
+    }
+
+    pub(crate) fn error_spanned<T: ToTokens, U: Display>(&mut self, tokens: T, message: U) {
+        self.push(syn::Error::new_spanned(tokens, message));
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub(crate) fn to_compile_errors(&self) -> TokenStream2 {
+        self.errors
+            .iter()
+            .map(syn::Error::to_compile_error)
+            .collect()
+    }
+}
+
+/// The Levenshtein distance between two strings, counted in `char`s.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, b_char) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if a_char == *b_char {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Suggest the closest of `candidates` to a misspelt `input`, formatted to be appended
+/// to an error message. Returns an empty string if nothing is close enough.
+pub(crate) fn did_you_mean(input: &str, candidates: &[&str]) -> String {
+    let input_lower = input.to_lowercase();
+    let threshold = (input.chars().count() / 3).max(2);
+
+    candidates
+        .iter()
+        .map(|candidate| {
+            (
+                edit_distance(&input_lower, &candidate.to_lowercase()),
+                candidate,
+            )
+        })
+        .filter(|(distance, _)| *distance <= threshold)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| format!(". Did you mean '{}'?", candidate))
+        .unwrap_or_default()
+}
+
 pub(crate) fn is_derive_attr(attr: &syn::Attribute) -> bool {
     !attr.path.segments.is_empty() && &*attr.path.segments[0].ident.to_string() == "derive"
 }
 
+pub(crate) fn is_enum_string_attr(attr: &syn::Attribute) -> bool {
+    !attr.path.segments.is_empty() && &*attr.path.segments[0].ident.to_string() == "enum_string"
+}
+
+pub(crate) fn is_serde_attr(attr: &syn::Attribute) -> bool {
+    !attr.path.segments.is_empty() && &*attr.path.segments[0].ident.to_string() == "serde"
+}
+
 pub(crate) fn guard_against_serde_derives(
     macro_name: &str,
     enum_name: &str,
     attrs: &[syn::Attribute],
+    errors: &mut Errors,
 ) {
     for enum_attr in attrs {
-        if is_derive_attr(enum_attr) {
-            use quote::ToTokens;
-
-            let meta = enum_attr.parse_meta();
-            if let Err(e) = meta {
-                let attr_name = enum_attr.path.clone().into_token_stream().to_string();
-                panic!(
-                    "Error parsing #[{}] invocation for {}: {:?}",
-                    &*attr_name, &*enum_name, e
-                );
+        if !is_derive_attr(enum_attr) {
+            continue;
+        }
+
+        // Derives may be given by path, e.g. `serde::Serialize`, which `parse_meta` rejects
+        let derives = match enum_attr.tts.clone().into_iter().next() {
+            Some(proc_macro2::TokenTree::Group(group)) => {
+                match Punctuated::<syn::Path, syn::Token![,]>::parse_terminated
+                    .parse2(group.stream())
+                {
+                    Ok(derives) => derives,
+                    Err(e) => {
+                        errors.push(e);
+                        continue;
+                    }
+                }
             }
+            _ => continue,
+        };
 
-            let meta = meta.unwrap();
-
-            let maybe_has_serde_attrs = match meta {
-                syn::Meta::List(ref meta_list) => meta_list
-                    .nested
-                    .iter()
-                    .filter(|part| {
-                        use syn::Meta::*;
-                        use syn::NestedMeta::*;
-
-                        match **part {
-                            Meta(Word(ref ident)) => {
-                                let ident_str = ident.to_string();
-
-                                &*ident_str == "Serialize" || &*ident_str == "Deserialize"
-                            }
-                            _ => false,
-                        }
-                    })
-                    .last()
-                    .is_some(),
-                _ => false,
+        for path in &derives {
+            let ident = match path.segments.last() {
+                Some(segment) => &segment.value().ident,
+                None => continue,
             };
+            let ident_str = ident.to_string();
 
-            if maybe_has_serde_attrs {
-                panic!("#[{}] cannot be used in conjunction with #[derive(Serialize)] or #[derive(Deserialize)]. Remove these derive attributes from {}.", macro_name, &*enum_name)
+            if &*ident_str == "Serialize" || &*ident_str == "Deserialize" {
+                errors.error_spanned(
+                    path,
+                    format!(
+                        "#[{}] cannot be used in conjunction with #[derive({})]. Remove this derive from {}.",
+                        macro_name, ident_str, enum_name
+                    ),
+                );
             }
         }
     }
 }
 
-pub(crate) fn get_enum_variant_names(syn_enum: &syn::ItemEnum) -> Vec<(syn::Ident, String)> {
-    syn_enum
-        .variants
+/// Gather the parameters of every `#[<name>(...)]` attribute in `attrs`.
+pub(crate) fn attr_params(
+    name: &str,
+    attrs: &[syn::Attribute],
+    errors: &mut Errors,
+) -> Vec<syn::NestedMeta> {
+    let mut params = Vec::new();
+
+    for attr in attrs {
+        if !attr.path.is_ident(name) {
+            continue;
+        }
+
+        match attr.parse_meta() {
+            Ok(syn::Meta::List(meta_list)) => params.extend(meta_list.nested),
+            Ok(_) => errors.error_spanned(attr, format!("Expected #[{}(...)]", name)),
+            Err(e) => errors.push(e),
+        }
+    }
+
+    params
+}
+
+/// Whether `attr` is a `#[cfg_attr(predicate, ...)]` which may expand to a `#[<name>]` attribute.
+pub(crate) fn is_cfg_attr_of(attr: &syn::Attribute, name: &str) -> bool {
+    if !attr.path.is_ident("cfg_attr") {
+        return false;
+    }
+
+    match attr.parse_meta() {
+        Ok(syn::Meta::List(meta_list)) => meta_list.nested.iter().skip(1).any(|nested| {
+            if let syn::NestedMeta::Meta(meta) = nested {
+                meta.name() == name
+            } else {
+                false
+            }
+        }),
+        _ => false,
+    }
+}
+
+/// Get the `#[cfg]` attributes in `attrs`, plus any `#[cfg_attr]` which may expand to one, so
+/// they can be repeated on the code generated for a variant.
+pub(crate) fn cfg_attrs(attrs: &[syn::Attribute]) -> Vec<syn::Attribute> {
+    attrs
         .iter()
-        .map(|v| (v.ident.clone(), v.ident.to_string()))
+        .filter(|attr| attr.path.is_ident("cfg") || is_cfg_attr_of(attr, "cfg"))
+        .cloned()
         .collect()
 }
+
+/// Reject `#[cfg_attr]`s wrapping any of the attributes in `names`. Derive macros only see the
+/// item once these have been expanded, but attribute macros see them unexpanded and have no way
+/// to evaluate the predicate.
+pub(crate) fn guard_against_cfg_attrs(
+    macro_name: &str,
+    names: &[&str],
+    attrs: &[syn::Attribute],
+    errors: &mut Errors,
+) {
+    for attr in attrs {
+        for name in names {
+            if is_cfg_attr_of(attr, name) {
+                errors.error_spanned(
+                    attr,
+                    format!(
+                        "#[{}] cannot evaluate #[cfg_attr(..., {}(...))]. Use #[derive(SerdeEnumString)] instead, which can.",
+                        macro_name, name
+                    ),
+                );
+            }
+        }
+    }
+}
+
+pub(crate) fn is_newtype_variant(variant: &syn::Variant) -> bool {
+    match variant.fields {
+        syn::Fields::Unnamed(ref fields) => fields.unnamed.len() == 1,
+        _ => false,
+    }
+}
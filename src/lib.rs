@@ -55,7 +55,7 @@
 //! }
 //! ```
 
-#![recursion_limit = "128"]
+#![recursion_limit = "256"]
 
 extern crate proc_macro;
 
@@ -63,15 +63,17 @@ use proc_macro::TokenStream;
 
 mod enum_string;
 mod helpers;
+mod match_mode;
+mod name_format;
 mod string_transform;
 
 /// Allows a C-style `enum` to be serialized as a string, useful for human-readable
 /// JSON.
 ///
-/// Takes two optional attributes:
+/// Takes the following optional attributes:
 ///
 /// * `transform = "<type>"` - transform the `enum` variants by name into the same
-///   formats as supported by the serde attribute `rename_all`. This includes:
+///   formats as supported by the serde attribute `rename_all`, and a few more. This includes:
 ///     * `lowercase` - makes variant names _lowercase_
 ///     * `UPPERCASE` - makes variant names _UPPERCASE_
 ///     * `PascalCase` - makes variant names _PascalCase_
@@ -79,17 +81,224 @@ mod string_transform;
 ///     * `snake_case` - makes variant names _snake\_case_
 ///     * `SCREAMING_SNAKE_CASE` - makes variant names _SCREAMING\_SNAKE\_CASE_
 ///     * `kebab-case` - makes variant names _kebab-case_
-///     * `SCREAMING-KEBAB-CASE` - makes variant names _SCREAMING-KEBAB-CASE_
+///     * `SCREAMING-KEBAB-CASE` (or `COBOL-CASE`) - makes variant names _SCREAMING-KEBAB-CASE_
+///     * `Train-Case` - makes variant names _Train-Case_
+///     * `dot.case` - makes variant names _dot.case_
+///     * `Title Case` - makes variant names _Title Case_
+///     * `flatcase` - makes variant names _flatcase_
+///     * `UPPERFLATCASE` - makes variant names _UPPERFLATCASE_
 ///
 ///   If unspecified, the `enum` variant names will be passed through unmodified.
 ///
+///   Names are split into words at each change from lowercase to uppercase, and acronyms are
+///   kept together, so `HTTPServer` becomes `http_server` in _snake\_case_. Digits belong to the
+///   word before them, so `Example2` becomes `example2`. Non-ASCII names are case-converted too.
+///
+/// * `prefix = "<text>"` and `suffix = "<text>"` - add the given text before or after the
+///   transformed name of every variant, so `prefix = "color."` gives `color.red`. The text is used
+///   verbatim unless `transform_affixes` is set.
+///
+/// * `name_format = "<template>"` - build each name from a template, in which `{variant}` is
+///   replaced by the variant name and `{enum}` by the `enum` name, each transformed separately.
+///   The rest of the template is used verbatim; write `{{` or `}}` for a literal brace. For
+///   example, `name_format = "{enum}::{variant}"` with `transform = "SCREAMING_SNAKE_CASE"`
+///   gives `COLOR::DARK_RED`. This cannot be combined with `prepend_enum_name`.
+///
+/// * `transform_affixes` - apply `transform` to the whole assembled name, including the prefix,
+///   suffix and `name_format` text, rather than to the names alone. With `suffix = "Color"` and
+///   `transform = "snake_case"`, this gives `dark_red_color` rather than `dark_redColor`.
+///   Punctuation other than `_`, `-` and spaces is kept verbatim and separates the text around
+///   it, so `prefix = "Color::"` with `SCREAMING_SNAKE_CASE` gives `COLOR::DARK_RED`.
+///
+/// * `split_digits` - treat runs of digits as words of their own when applying `transform`, so
+///   that `Example2` becomes `example_2` in _snake\_case_.
+///
 /// * `prepend_enum_name` - Add the name of the `enum` to the values for each variant. This will
 ///   be prepended to the variant name before running the transform described above.
 ///
+/// * `matching = "<mode>"` - control how strictly input is compared against the variant strings
+///   when deserializing. Serialization always uses the canonical string. One of:
+///     * `exact` - the default; input must match exactly
+///     * `case_insensitive` - input and variant strings are compared after Unicode lowercasing
+///     * `lenient` - as `case_insensitive`, but surrounding whitespace is also trimmed and `_`,
+///       `-` and space are treated as equivalent
+///
+///   Compilation fails if two variants become indistinguishable under the chosen mode.
+///
+/// * `repr = "<type>"` - serialize each variant as its explicit or implicit discriminant rather
+///   than as a string. The type is one of `u8`, `u16`, `u32`, `u64`, `i8`, `i16`, `i32` or `i64`,
+///   and every discriminant must fit in it. When deserializing, integers outside the range of the
+///   type are treated like any other unknown value. This cannot be combined with an `other`
+///   variant, and every variant, including skipped ones, must be a unit variant so it can be
+///   cast to the type. Generic `enum`s therefore can't use `repr`, as they need a skipped
+///   variant holding a `PhantomData`.
+///
+/// * `dual_input = "<form>"` - with `repr`, accept either the string or the discriminant of a
+///   variant when deserializing. The form is either `string` or `repr`, and chooses which of the
+///   two is serialized. This relies on `Deserializer::deserialize_any`, so it only works with
+///   self-describing formats such as JSON.
+///
+/// * `compact` - use the string representation only for human-readable formats (as reported
+///   by `Serializer::is_human_readable`), and an integer for everything else. The integer is
+///   the discriminant if `repr` is set, or otherwise the `u32` index of the variant in
+///   declaration order. This cannot be combined with `dual_input` or an `other` variant.
+///
+/// * `unit_variant` - serialize each variant as a unit variant of the `enum`, named by its
+///   string and numbered by its `u32` index in declaration order, and deserialize it with
+///   `Deserializer::deserialize_enum`. Formats which tell `enum`s apart from strings, such as
+///   RON, or which identify variants by index then treat the value as an `enum`, while JSON
+///   still sees a plain string. This cannot be combined with `repr`, `compact` or an `other`
+///   variant.
+///
+/// * `suggest` - when a string isn't recognised, append the closest of the accepted values (by
+///   edit distance, ignoring case) to the deserialization error, as in `did you mean
+///   "snake_case"?`. All equally close values are suggested, and none if they're all too far
+///   off. There's nothing to suggest if the `enum` has an `other` or `default` variant.
+///
+/// * `crate = "<path>"` - the path to the `serde` crate used by the generated code. Defaults to
+///   `::serde`; set this if `serde` has been renamed or is only available through a re-export.
+///
+/// * `on_unknown = "<path>"` - a function with the signature `fn(&str)` which is called with the
+///   unrecognised value (formatted as a string) whenever a value deserializes into the `other`
+///   or `default` variant described below. Useful for logging or counting fallbacks.
+///
+/// * `with_module = "<name>"` - also generate a module with the given name alongside the `enum`,
+///   with `serialize` and `deserialize` functions for use with `#[serde(with = "<name>")]` on a
+///   field. It contains the nested modules `option`, `vec` and `map_key` for fields of type
+///   `Option<Enum>`, `Vec<Enum>` and maps keyed by the `enum` (anything which can be iterated by
+///   reference and collected into), e.g. `#[serde(with = "<name>::map_key")]`. The module uses
+///   `super::*`, so the `enum` must be declared directly in a module rather than in a function,
+///   and it cannot be generic.
+///
+/// * `skip_serde_impls` - don't implement `Serialize` or `Deserialize` for the `enum` itself,
+///   which is mostly useful with `with_module` for an `enum` which already has serde impls of its
+///   own. In this mode any `#[serde(...)]` attributes are left for serde's derives.
+///
+/// * `display` - also implement `std::fmt::Display`, writing the string for each variant. This
+///   is always the string, even when `repr` or `compact` serialize an integer instead. This
+///   cannot be used if any variant is marked `skip`.
+///
+/// * `from_str` - also implement `std::str::FromStr`, following the same rules as deserialization.
+///   Failures are reported with a generated `Parse<Enum>Error` type, which implements
+///   `std::error::Error` and lists the accepted values in its message.
+///
+/// * `as_str` - also generate an inherent `as_str(&self) -> &'static str` method returning the
+///   string for each variant, which like `display` is never the integer used by `repr` or
+///   `compact`. If the `enum` has an `other` variant the returned string borrows from `self`
+///   instead. Like `display`, this cannot be used if any variant is marked `skip`.
+///
+/// * `variants` - also generate `VARIANTS` and `NAMES` associated constants listing every variant
+///   and its string in declaration order, and an `iter()` associated function over `VARIANTS`.
+///   An `other` variant is not included.
+///
+/// These may also be given in an `#[enum_string(...)]` attribute on the `enum`, as is done for
+/// the [`SerdeEnumString`](derive.SerdeEnumString.html) derive.
+///
+/// Individual variants can be customised with the `#[enum_string(...)]` attribute:
+///
+/// * `rename = "<name>"` - use the given string for this variant instead of the generated one.
+///   The string is used verbatim; none of the naming options above are applied to it.
+/// * `alias = "<name>"` - also accept the given string when deserializing this variant. May be
+///   repeated. Aliases are never used for serialization and are not listed in error messages.
+/// * `other` - mark a single-field tuple variant such as `Unknown(String)` as a catch-all. Any
+///   string not matching another variant deserializes into it, and it serializes back to the
+///   wrapped string verbatim. The field type must implement `AsRef<str>` and `From<String>`.
+///   At most one variant may be marked `other`, and it is the only variant allowed to have fields.
+/// * `skip` - leave this variant out of serialization and deserialization entirely. Serializing
+///   it returns an error, and as it has no string the `display` and `as_str` options can't be
+///   used. Skipped variants may have fields, which allows generic `enum`s to hold a
+///   `PhantomData` marker for their type parameters.
+/// * `default` (or `fallback`) - mark a unit variant which any unrecognised string deserializes
+///   into. The variant keeps its own name for serialization. This cannot be combined with `other`.
+///
+/// Compilation fails if two variants end up with the same string, whether through `transform`,
+/// `prepend_enum_name`, `rename` or `alias`. Variants with differing `#[cfg(...)]` attributes
+/// may share a string as long as they're never compiled together; the error is only raised
+/// when both are enabled.
+///
+/// Variants may be conditionally compiled with `#[cfg(...)]`, in which case everything
+/// generated for them, including their entries in `VARIANTS`, `NAMES` and error messages, is
+/// conditional too. The `other` and `default` variants can't be conditional. The attribute
+/// macro runs before `#[cfg_attr(...)]` is evaluated, so it reports an error for a `cfg_attr`
+/// that would add `#[enum_string(...)]` or `#[serde(...)]`; the derive form has no such
+/// restriction.
+///
+/// `enum`s without any variants are supported; they reject every value when deserializing.
+/// `#[non_exhaustive]` is kept on the `enum` but doesn't change the generated code, so values
+/// added in a newer version of the `enum` are rejected like any other unknown value. Mark a
+/// variant as `default` or `other` if older builds should accept them instead.
+///
+/// ```
+/// use serde_json_helpers::serde_enum_string;
+///
+/// #[serde_enum_string(transform = "lowercase")]
+/// #[derive(Debug, PartialEq)]
+/// enum ContentType {
+///     #[enum_string(rename = "application/json")]
+///     Json,
+///     #[enum_string(alias = "plain")]
+///     Text,
+/// }
+///
+/// assert_eq!(serde_json::to_string(&ContentType::Json).unwrap(), "\"application/json\"");
+/// assert_eq!(serde_json::to_string(&ContentType::Text).unwrap(), "\"text\"");
+/// assert_eq!(serde_json::from_str::<ContentType>("\"plain\"").unwrap(), ContentType::Text);
+/// ```
+///
+/// Strings are accepted however the format hands them over, whether borrowed, owned or as raw
+/// bytes (such as MessagePack's `bin` or CBOR byte strings), as long as they're valid UTF-8.
+///
+/// The generated `impl`s are wrapped in an anonymous `const` scope so that none of their helpers
+/// leak into the surrounding module, and only refer to `core` and `serde` by absolute path. Those
+/// options which need to allocate (`other`, `matching`, `on_unknown` with `repr`, `suggest` and
+/// `from_str`) additionally require `std`.
+///
+/// To ease migrating from serde's own derives, a subset of serde's attributes is also honoured
+/// and then removed from the `enum`, so only the derive needs replacing:
+///
+/// * `#[serde(rename_all = "...")]` on the `enum` works like `transform`, but converts names
+///   exactly as serde does, so `HTTPServer` still becomes `h_t_t_p_server` in _snake\_case_.
+/// * `#[serde(crate = "...")]` on the `enum` works like `crate`.
+/// * `#[serde(rename = "...")]`, `#[serde(alias = "...")]` and `#[serde(skip)]` on a variant work
+///   like their `#[enum_string(...)]` equivalents, and `#[serde(other)]` works like `default`.
+///
+/// Any other serde attribute is reported as an error.
+///
+/// Invalid options are reported as compile errors pointing at the offending attribute, with a
+/// suggestion if a key or value looks like a misspelling of a known one.
+///
 /// Note that this macro is incompatible with existing `Serialize` and `Deserialize` `impl`s.
-/// If a Serialize or Deserialize derive is detected, this macro will panic, but if you `impl` them
-/// directly you will just get normal compiler issues which you're on your own to figure out.
+/// If a Serialize or Deserialize derive is detected, this macro will report an error, but if you
+/// `impl` them directly you will just get normal compiler issues which you're on your own to
+/// figure out.
 #[proc_macro_attribute]
 pub fn serde_enum_string(attr: TokenStream, item: TokenStream) -> TokenStream {
     enum_string::serde_enum_string_impl(attr, item)
 }
+
+/// The derive form of [`serde_enum_string`](attr.serde_enum_string.html), which generates exactly
+/// the same `impl`s but can be placed alongside other derives in any order.
+///
+/// Options which would be passed to the attribute macro are instead given in an
+/// `#[enum_string(...)]` attribute on the `enum` itself. Individual variants are customised
+/// with `#[enum_string(...)]` in the same way as for the attribute macro. As with the attribute
+/// macro, it can't be combined with serde's own `Serialize` or `Deserialize` derives.
+///
+/// ```
+/// use serde_json_helpers::SerdeEnumString;
+///
+/// #[derive(Debug, PartialEq, SerdeEnumString)]
+/// #[enum_string(transform = "kebab-case", display)]
+/// enum LogLevel {
+///     Warning,
+///     #[enum_string(alias = "err")]
+///     CriticalError,
+/// }
+///
+/// assert_eq!(LogLevel::CriticalError.to_string(), "critical-error");
+/// assert_eq!(serde_json::from_str::<LogLevel>("\"err\"").unwrap(), LogLevel::CriticalError);
+/// ```
+#[proc_macro_derive(SerdeEnumString, attributes(enum_string, serde))]
+pub fn derive_serde_enum_string(item: TokenStream) -> TokenStream {
+    enum_string::derive_serde_enum_string_impl(item)
+}
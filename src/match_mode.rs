@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0+
+
+use quote::quote;
+use syn::export::TokenStream2;
+
+#[derive(Default)]
+pub(crate) enum MatchMode {
+    #[default]
+    Exact,
+    CaseInsensitive,
+    Lenient,
+}
+
+impl MatchMode {
+    pub(crate) const NAMES: &'static [&'static str] = &["exact", "case_insensitive", "lenient"];
+
+    pub(crate) fn from_str(s: &str) -> Option<MatchMode> {
+        use self::MatchMode::*;
+
+        match s {
+            "exact" => Some(Exact),
+            "case_insensitive" => Some(CaseInsensitive),
+            "lenient" => Some(Lenient),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn is_exact(&self) -> bool {
+        matches!(self, MatchMode::Exact)
+    }
+
+    /// Normalise a string at macro expansion time. This must produce exactly the same output
+    /// as the code generated by `normalize_tokens` does at runtime.
+    pub(crate) fn normalize(&self, input: &str) -> String {
+        use self::MatchMode::*;
+
+        match self {
+            Exact => input.to_string(),
+            CaseInsensitive => input.chars().flat_map(char::to_lowercase).collect(),
+            Lenient => input
+                .trim()
+                .chars()
+                .flat_map(char::to_lowercase)
+                .map(|c| match c {
+                    '-' | ' ' => '_',
+                    c => c,
+                })
+                .collect(),
+        }
+    }
+
+    /// Generate an expression normalising the `&str` expression `input` at runtime. The
+    /// expression evaluates to something that derefs to `str`.
+    pub(crate) fn normalize_tokens(&self, input: TokenStream2) -> TokenStream2 {
+        use self::MatchMode::*;
+
+        match self {
+            Exact => input,
+            CaseInsensitive => quote! {
+                #input
+                    .chars()
+                    .flat_map(char::to_lowercase)
+                    .collect::<::std::string::String>()
+            },
+            Lenient => quote! {
+                #input
+                    .trim()
+                    .chars()
+                    .flat_map(char::to_lowercase)
+                    .map(|c| match c {
+                        '-' | ' ' => '_',
+                        c => c,
+                    })
+                    .collect::<::std::string::String>()
+            },
+        }
+    }
+}
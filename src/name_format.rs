@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0+
+
+enum Segment {
+    Literal(String),
+    Enum,
+    Variant,
+}
+
+/// A `name_format` template such as `{enum}.{variant}`. Braces are escaped by doubling them.
+pub(crate) struct NameFormat {
+    segments: Vec<Segment>,
+}
+
+impl NameFormat {
+    pub(crate) const PLACEHOLDERS: &'static [&'static str] = &["enum", "variant"];
+
+    pub(crate) fn parse(input: &str) -> Result<NameFormat, String> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut chars = input.chars();
+
+        while let Some(chr) = chars.next() {
+            match chr {
+                '{' if chars.as_str().starts_with('{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.as_str().starts_with('}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let rest = chars.as_str();
+                    let end = rest
+                        .find('}')
+                        .ok_or_else(|| "unclosed '{' in 'name_format'".to_string())?;
+                    let placeholder = &rest[..end];
+
+                    let segment = match placeholder {
+                        "enum" => Segment::Enum,
+                        "variant" => Segment::Variant,
+                        _ => {
+                            return Err(format!(
+                                "unknown placeholder '{{{}}}' in 'name_format'{}",
+                                placeholder,
+                                crate::helpers::did_you_mean(placeholder, Self::PLACEHOLDERS)
+                            ));
+                        }
+                    };
+
+                    if !literal.is_empty() {
+                        segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                    }
+
+                    segments.push(segment);
+                    chars = rest[end + 1..].chars();
+                }
+                '}' => return Err("unmatched '}' in 'name_format'".to_string()),
+                chr => literal.push(chr),
+            }
+        }
+
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+
+        if !segments
+            .iter()
+            .any(|segment| matches!(segment, Segment::Variant))
+        {
+            return Err("'name_format' must contain '{variant}'".to_string());
+        }
+
+        Ok(NameFormat { segments })
+    }
+
+    /// Fill in the template, passing the enum and variant names through `convert` first.
+    /// Literal text is copied verbatim.
+    pub(crate) fn render<F: Fn(&str) -> String>(
+        &self,
+        enum_name: &str,
+        variant_name: &str,
+        convert: F,
+    ) -> String {
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Literal(literal) => literal.clone(),
+                Segment::Enum => convert(enum_name),
+                Segment::Variant => convert(variant_name),
+            })
+            .collect()
+    }
+}
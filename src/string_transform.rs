@@ -9,9 +9,43 @@ pub(crate) enum StringTransform {
     ScreamingSnakeCase,
     KebabCase,
     ScreamingKebabCase,
+    TrainCase,
+    DotCase,
+    TitleCase,
+    FlatCase,
+    UpperFlatCase,
 }
 
 impl StringTransform {
+    pub(crate) const NAMES: &'static [&'static str] = &[
+        "lowercase",
+        "UPPERCASE",
+        "PascalCase",
+        "camelCase",
+        "snake_case",
+        "SCREAMING_SNAKE_CASE",
+        "kebab-case",
+        "SCREAMING-KEBAB-CASE",
+        "COBOL-CASE",
+        "Train-Case",
+        "dot.case",
+        "Title Case",
+        "flatcase",
+        "UPPERFLATCASE",
+    ];
+
+    /// The subset of transforms supported by serde's `rename_all`
+    pub(crate) const SERDE_NAMES: &'static [&'static str] = &[
+        "lowercase",
+        "UPPERCASE",
+        "PascalCase",
+        "camelCase",
+        "snake_case",
+        "SCREAMING_SNAKE_CASE",
+        "kebab-case",
+        "SCREAMING-KEBAB-CASE",
+    ];
+
     pub(crate) fn from_str(s: &str) -> Option<StringTransform> {
         use self::StringTransform::*;
 
@@ -23,141 +57,190 @@ impl StringTransform {
             "snake_case" => Some(SnakeCase),
             "SCREAMING_SNAKE_CASE" => Some(ScreamingSnakeCase),
             "kebab-case" => Some(KebabCase),
-            "SCREAMING-KEBAB-CASE" => Some(ScreamingKebabCase),
+            "SCREAMING-KEBAB-CASE" | "COBOL-CASE" => Some(ScreamingKebabCase),
+            "Train-Case" => Some(TrainCase),
+            "dot.case" => Some(DotCase),
+            "Title Case" => Some(TitleCase),
+            "flatcase" => Some(FlatCase),
+            "UPPERFLATCASE" => Some(UpperFlatCase),
             _ => None,
         }
     }
 
-    pub(crate) fn transform<S: ToString>(&self, input: S) -> String {
+    /// Transform `input` into this case. Unless `split_digits` is set, digits stay attached to
+    /// the word before them, as they do with serde's `rename_all`.
+    pub(crate) fn transform<S: ToString>(&self, input: S, split_digits: bool) -> String {
         use self::StringTransform::*;
 
         let input_string = input.to_string();
 
         match self {
-            Lowercase => Self::transform_lowercase(&*input_string),
-            Uppercase => Self::transform_uppercase(&*input_string),
-            PascalCase => Self::transform_pascalcase(&*input_string),
-            CamelCase => Self::transform_camelcase(&*input_string),
-            SnakeCase => Self::transform_snakecase(&*input_string),
-            ScreamingSnakeCase => Self::transform_screaming_snakecase(&*input_string),
-            KebabCase => Self::transform_kebabcase(&*input_string),
-            ScreamingKebabCase => Self::transform_screaming_kebabcase(&*input_string),
+            Lowercase => input_string.to_lowercase(),
+            Uppercase => input_string.to_uppercase(),
+            PascalCase => Self::split_words(&input_string, split_digits)
+                .into_iter()
+                .map(Self::capitalize)
+                .collect(),
+            CamelCase => Self::split_words(&input_string, split_digits)
+                .into_iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.to_lowercase()
+                    } else {
+                        Self::capitalize(word)
+                    }
+                })
+                .collect(),
+            SnakeCase => Self::join_words(&input_string, split_digits, str::to_lowercase, "_"),
+            ScreamingSnakeCase => {
+                Self::join_words(&input_string, split_digits, str::to_uppercase, "_")
+            }
+            KebabCase => Self::join_words(&input_string, split_digits, str::to_lowercase, "-"),
+            ScreamingKebabCase => {
+                Self::join_words(&input_string, split_digits, str::to_uppercase, "-")
+            }
+            TrainCase => Self::join_words(&input_string, split_digits, Self::capitalize, "-"),
+            DotCase => Self::join_words(&input_string, split_digits, str::to_lowercase, "."),
+            TitleCase => Self::join_words(&input_string, split_digits, Self::capitalize, " "),
+            FlatCase => Self::join_words(&input_string, split_digits, str::to_lowercase, ""),
+            UpperFlatCase => Self::join_words(&input_string, split_digits, str::to_uppercase, ""),
         }
     }
 
-    fn split_by_case(input: &str) -> Vec<&str> {
-        let split_posns: Vec<usize> = input
-            .chars()
-            .enumerate()
-            .filter_map(|(pos, chr)| {
-                if chr.is_ascii_uppercase() {
-                    Some(pos)
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        let mut out = Vec::new();
+    /// Transform `input` exactly as serde's `rename_all` does for variant names, so that
+    /// `#[serde(rename_all = "...")]` keeps producing the same strings. Unlike `transform`, this
+    /// doesn't keep acronyms together, so `HTTPServer` becomes `h_t_t_p_server` in snake case.
+    pub(crate) fn transform_like_serde(&self, input: &str) -> String {
+        use self::StringTransform::*;
 
-        if !(&split_posns).is_empty() {
-            let mut last_pos = 0;
+        let snake_case = || {
+            let mut out = String::new();
 
-            for pos in &split_posns {
-                let pos = *pos;
-                if pos != last_pos {
-                    out.push(&input[last_pos..pos]);
+            for (i, chr) in input.char_indices() {
+                if i > 0 && chr.is_uppercase() {
+                    out.push('_');
                 }
 
-                last_pos = pos;
+                out.push(chr.to_ascii_lowercase());
             }
 
-            out.push(&input[last_pos..]);
-        }
-
-        out
-    }
-
-    fn transform_lowercase(input: &str) -> String {
-        input.to_ascii_lowercase()
-    }
+            out
+        };
 
-    fn transform_uppercase(input: &str) -> String {
-        input.to_ascii_uppercase()
+        match self {
+            Lowercase => input.to_ascii_lowercase(),
+            Uppercase => input.to_ascii_uppercase(),
+            PascalCase => input.to_string(),
+            CamelCase => {
+                let mut chars = input.chars();
+
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => String::new(),
+                }
+            }
+            SnakeCase => snake_case(),
+            ScreamingSnakeCase => snake_case().to_ascii_uppercase(),
+            KebabCase => snake_case().replace('_', "-"),
+            ScreamingKebabCase => snake_case().to_ascii_uppercase().replace('_', "-"),
+            _ => self.transform(input, false),
+        }
     }
 
-    fn transform_pascalcase(input: &str) -> String {
-        let parts = Self::split_by_case(input);
-
-        parts
-            .into_iter()
-            .map(|part| {
-                let mut new_part = part.to_ascii_lowercase();
+    /// Split an identifier into words. A new word starts at each uppercase letter following a
+    /// lowercase one, at the last capital of a run of them when it's followed by lowercase
+    /// (so `HTTPServer` is `HTTP` and `Server`), and at any `_`, `-` or whitespace, which are
+    /// themselves dropped. Letters without case, such as CJK, behave like lowercase ones.
+    fn split_words(input: &str, split_digits: bool) -> Vec<&str> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Class {
+            Upper,
+            Lower,
+            Digit,
+            Separator,
+        }
 
-                let s = new_part.get_mut(0..1);
-                s.map(|s| {
-                    s.to_ascii_uppercase();
+        fn classify(chr: char) -> Class {
+            if chr == '_' || chr == '-' || chr.is_whitespace() {
+                Class::Separator
+            } else if chr.is_uppercase() {
+                Class::Upper
+            } else if chr.is_numeric() {
+                Class::Digit
+            } else {
+                Class::Lower
+            }
+        }
 
-                    &*s
-                })
-                .expect("Failed to get start of string segment");
+        let chars: Vec<(usize, char)> = input.char_indices().collect();
+        let mut words = Vec::new();
+        let mut word_start: Option<usize> = None;
 
-                new_part
-            })
-            .collect()
-    }
+        for (i, &(pos, chr)) in chars.iter().enumerate() {
+            let class = classify(chr);
 
-    fn transform_camelcase(input: &str) -> String {
-        let mut out = Self::transform_pascalcase(input);
+            if class == Class::Separator {
+                if let Some(start) = word_start.take() {
+                    words.push(&input[start..pos]);
+                }
 
-        let s = out.get_mut(0..1);
-        s.map(|s| {
-            s.to_ascii_lowercase();
+                continue;
+            }
 
-            &*s
-        })
-        .expect("Failed to get start of string");
+            let start = match word_start {
+                Some(start) => start,
+                None => {
+                    word_start = Some(pos);
+                    continue;
+                }
+            };
 
-        out
-    }
+            let prev = classify(chars[i - 1].1);
+            let next = chars.get(i + 1).map(|&(_, chr)| classify(chr));
 
-    fn transform_snakecase(input: &str) -> String {
-        let parts = Self::split_by_case(input);
+            let boundary = match (prev, class) {
+                (Class::Lower, Class::Upper) | (Class::Digit, Class::Upper) => true,
+                (Class::Upper, Class::Upper) => next == Some(Class::Lower),
+                (Class::Digit, _) | (_, Class::Digit) => split_digits && prev != class,
+                _ => false,
+            };
 
-        parts
-            .into_iter()
-            .map(|s| s.to_ascii_lowercase())
-            .collect::<Vec<String>>()
-            .join("_")
-    }
+            if boundary {
+                words.push(&input[start..pos]);
+                word_start = Some(pos);
+            }
+        }
 
-    fn transform_screaming_snakecase(input: &str) -> String {
-        let parts = Self::split_by_case(input);
+        if let Some(start) = word_start {
+            words.push(&input[start..]);
+        }
 
-        parts
-            .into_iter()
-            .map(|s| s.to_ascii_uppercase())
-            .collect::<Vec<String>>()
-            .join("_")
+        words
     }
 
-    fn transform_kebabcase(input: &str) -> String {
-        let parts = Self::split_by_case(input);
+    fn capitalize(word: &str) -> String {
+        let mut chars = word.chars();
 
-        parts
-            .into_iter()
-            .map(|s| s.to_ascii_lowercase())
-            .collect::<Vec<String>>()
-            .join("-")
+        match chars.next() {
+            Some(first) => first
+                .to_uppercase()
+                .chain(chars.flat_map(char::to_lowercase))
+                .collect(),
+            None => String::new(),
+        }
     }
 
-    fn transform_screaming_kebabcase(input: &str) -> String {
-        let parts = Self::split_by_case(input);
-
-        parts
+    fn join_words(
+        input: &str,
+        split_digits: bool,
+        convert: fn(&str) -> String,
+        separator: &str,
+    ) -> String {
+        Self::split_words(input, split_digits)
             .into_iter()
-            .map(|s| s.to_ascii_uppercase())
+            .map(convert)
             .collect::<Vec<String>>()
-            .join("-")
+            .join(separator)
     }
 }
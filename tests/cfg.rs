@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0+
+
+use serde_json_helpers::{serde_enum_string, SerdeEnumString};
+use std::str::FromStr;
+
+// Integration tests are always built with `test`, so `not(test)` stands in for a disabled feature
+#[serde_enum_string(transform = "snake_case", from_str, variants)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TestEnum {
+    Variant,
+    #[cfg(not(test))]
+    Disabled,
+    #[cfg(test)]
+    ExampleVariant,
+}
+
+#[test]
+fn ignores_disabled_variants() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnum::ExampleVariant).unwrap(),
+        serde_json::to_string("example_variant").unwrap()
+    );
+    assert_eq!(
+        serde_json::from_str::<TestEnum>("\"example_variant\"").unwrap(),
+        TestEnum::ExampleVariant
+    );
+
+    assert!(serde_json::from_str::<TestEnum>("\"disabled\"").is_err());
+    assert!(TestEnum::from_str("disabled").is_err());
+
+    assert_eq!(
+        TestEnum::VARIANTS,
+        &[TestEnum::Variant, TestEnum::ExampleVariant]
+    );
+    assert_eq!(TestEnum::NAMES, &["variant", "example_variant"]);
+}
+
+#[test]
+fn leaves_disabled_variants_out_of_errors() {
+    let err = serde_json::from_str::<TestEnum>("\"disabled\"")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("one of: \"variant\", \"example_variant\""));
+
+    let err = TestEnum::from_str("disabled").unwrap_err().to_string();
+    assert_eq!(
+        err,
+        "invalid value \"disabled\", expected one of: \"variant\", \"example_variant\""
+    );
+}
+
+#[serde_enum_string(repr = "u8")]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TestEnumRepr {
+    Variant = 1,
+    #[cfg(not(test))]
+    Disabled = 300,
+    ExampleVariant,
+}
+
+#[test]
+fn ignores_disabled_repr_variants() {
+    assert_eq!(
+        serde_json::to_string(&TestEnumRepr::ExampleVariant).unwrap(),
+        "2"
+    );
+    assert_eq!(
+        serde_json::from_str::<TestEnumRepr>("1").unwrap(),
+        TestEnumRepr::Variant
+    );
+
+    let err = serde_json::from_str::<TestEnumRepr>("3")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("one of: 1, 2"));
+}
+
+/// Variants which are never compiled together may share a string
+#[derive(SerdeEnumString, Debug, PartialEq)]
+#[enum_string(transform = "kebab-case")]
+enum Backend {
+    #[cfg(test)]
+    #[enum_string(rename = "default")]
+    Native,
+    #[cfg(not(test))]
+    #[enum_string(rename = "default")]
+    Fallback,
+    #[enum_string(skip)]
+    #[cfg(not(test))]
+    Internal,
+}
+
+#[test]
+fn allows_exclusive_variants_to_share_strings() {
+    assert_eq!(
+        serde_json::to_string(&Backend::Native).unwrap(),
+        "\"default\""
+    );
+    assert_eq!(
+        serde_json::from_str::<Backend>("\"default\"").unwrap(),
+        Backend::Native
+    );
+}
@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0+
+
+mod util;
+
+use serde::{Deserialize, Serialize};
+use serde_json_helpers::serde_enum_string;
+
+test_enum_variant!(TestEnum, (transform = "snake_case", compact));
+
+#[test]
+fn serializes_string_when_human_readable() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnum::ExampleVariant).unwrap(),
+        serde_json::to_string("example_variant").unwrap()
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnum>("\"example_variant\"").unwrap(),
+        TestEnum::ExampleVariant
+    );
+}
+
+#[test]
+fn serializes_index_when_compact() {
+    // bincode writes a `u32` as four little-endian bytes
+    assert_eq!(
+        bincode::serialize(&TestEnum::Variant).unwrap(),
+        [0, 0, 0, 0]
+    );
+    assert_eq!(
+        bincode::serialize(&TestEnum::Example2).unwrap(),
+        [2, 0, 0, 0]
+    );
+
+    assert_eq!(
+        bincode::deserialize::<TestEnum>(&[1, 0, 0, 0]).unwrap(),
+        TestEnum::ExampleVariant
+    );
+    assert!(bincode::deserialize::<TestEnum>(&[3, 0, 0, 0]).is_err());
+
+    // postcard writes it as a varint
+    assert_eq!(postcard::to_allocvec(&TestEnum::Example2).unwrap(), [2]);
+    assert_eq!(
+        postcard::from_bytes::<TestEnum>(&[1]).unwrap(),
+        TestEnum::ExampleVariant
+    );
+    assert!(postcard::from_bytes::<TestEnum>(&[3]).is_err());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Wrapper {
+    first: TestEnum,
+    count: u16,
+    second: TestEnum,
+}
+
+#[test]
+fn round_trips_inside_structs_when_compact() {
+    let wrapper = Wrapper {
+        first: TestEnum::ExampleVariant,
+        count: 7,
+        second: TestEnum::Example2,
+    };
+
+    let bytes = bincode::serialize(&wrapper).unwrap();
+    assert_eq!(bytes, [1, 0, 0, 0, 7, 0, 2, 0, 0, 0]);
+    assert_eq!(bincode::deserialize::<Wrapper>(&bytes).unwrap(), wrapper);
+
+    let bytes = postcard::to_allocvec(&wrapper).unwrap();
+    assert_eq!(bytes, [1, 7, 2]);
+    assert_eq!(postcard::from_bytes::<Wrapper>(&bytes).unwrap(), wrapper);
+}
+
+#[serde_enum_string(transform = "snake_case", repr = "u8", compact)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TestEnumRepr {
+    Variant = 10,
+    ExampleVariant = 20,
+}
+
+#[test]
+fn serializes_discriminant_when_compact_with_repr() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumRepr::Variant).unwrap(),
+        serde_json::to_string("variant").unwrap()
+    );
+
+    assert_eq!(
+        bincode::serialize(&TestEnumRepr::ExampleVariant).unwrap(),
+        [20]
+    );
+    assert_eq!(
+        bincode::deserialize::<TestEnumRepr>(&[10]).unwrap(),
+        TestEnumRepr::Variant
+    );
+    assert!(bincode::deserialize::<TestEnumRepr>(&[0]).is_err());
+
+    assert_eq!(
+        postcard::from_bytes::<TestEnumRepr>(
+            &postcard::to_allocvec(&TestEnumRepr::Variant).unwrap()
+        )
+        .unwrap(),
+        TestEnumRepr::Variant
+    );
+}
+
+#[test]
+fn only_expects_values_for_the_format() {
+    let err = serde_json::from_str::<TestEnum>("1")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("one of: \"variant\", \"example_variant\", \"example2\""));
+    assert!(!err.contains("0, 1"));
+
+    let err = bincode::deserialize::<TestEnum>(&[3, 0, 0, 0])
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("one of: 0, 1, 2"));
+    assert!(!err.contains("\"variant\""));
+}
+
+#[serde_enum_string(transform = "snake_case", compact, suggest)]
+#[derive(Debug, PartialEq)]
+enum TestEnumSuggest {
+    First,
+    SecondOne,
+}
+
+#[test]
+fn suggests_only_strings_when_human_readable() {
+    let err = serde_json::from_str::<TestEnumSuggest>("\"secnd_one\"")
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.contains("one of: \"first\", \"second_one\"; did you mean \"second_one\"?"));
+}
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0+
+
+use serde_json_helpers::{serde_enum_string, SerdeEnumString};
+use std::str::FromStr;
+
+#[derive(Debug, SerdeEnumString, PartialEq, Clone, Copy)]
+#[enum_string(transform = "snake_case", from_str)]
+enum TestEnum {
+    Variant,
+    ExampleVariant,
+    #[enum_string(rename = "second", alias = "2")]
+    Example2,
+}
+
+#[test]
+fn can_serialize_derived() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnum::ExampleVariant).unwrap(),
+        serde_json::to_string("example_variant").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnum::Example2).unwrap(),
+        serde_json::to_string("second").unwrap()
+    );
+}
+
+#[test]
+fn can_deserialize_derived() {
+    assert_eq!(
+        serde_json::from_str::<TestEnum>("\"variant\"").unwrap(),
+        TestEnum::Variant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnum>("\"2\"").unwrap(),
+        TestEnum::Example2
+    );
+
+    assert!(serde_json::from_str::<TestEnum>("\"Variant\"").is_err());
+    assert_eq!(TestEnum::from_str("second").unwrap(), TestEnum::Example2);
+}
+
+#[derive(SerdeEnumString, Debug, PartialEq)]
+#[enum_string(transform = "kebab-case")]
+#[enum_string(prefix = "log/", variants)]
+enum LogLevel {
+    Warning,
+    CriticalError,
+}
+
+#[test]
+fn merges_repeated_option_attributes() {
+    assert_eq!(LogLevel::NAMES, &["log/warning", "log/critical-error"]);
+
+    assert_eq!(
+        serde_json::from_str::<LogLevel>("\"log/critical-error\"").unwrap(),
+        LogLevel::CriticalError
+    );
+}
+
+#[derive(SerdeEnumString, Debug, PartialEq)]
+enum Untransformed {
+    Variant,
+    ExampleVariant,
+}
+
+#[test]
+fn can_derive_without_options() {
+    assert_eq!(
+        &*serde_json::to_string(&Untransformed::ExampleVariant).unwrap(),
+        serde_json::to_string("ExampleVariant").unwrap()
+    );
+
+    assert_eq!(
+        serde_json::from_str::<Untransformed>("\"Variant\"").unwrap(),
+        Untransformed::Variant
+    );
+}
+
+#[serde_enum_string(transform = "UPPERCASE")]
+#[enum_string(prefix = "X-")]
+#[derive(Debug, PartialEq)]
+enum AttributeWithEnumOptions {
+    Variant,
+}
+
+#[test]
+fn attribute_macro_accepts_enum_options() {
+    assert_eq!(
+        &*serde_json::to_string(&AttributeWithEnumOptions::Variant).unwrap(),
+        serde_json::to_string("X-VARIANT").unwrap()
+    );
+}
@@ -84,6 +84,46 @@ fn can_deserialize_uppercase() {
     );
 }
 
+test_enum_variant!(TestEnumPascalCase, (transform = "PascalCase"));
+
+#[test]
+fn can_deserialize_pascal_case() {
+    assert_eq!(
+        serde_json::from_str::<TestEnumPascalCase>("\"Variant\"").unwrap(),
+        TestEnumPascalCase::Variant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumPascalCase>("\"ExampleVariant\"").unwrap(),
+        TestEnumPascalCase::ExampleVariant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumPascalCase>("\"Example2\"").unwrap(),
+        TestEnumPascalCase::Example2
+    );
+}
+
+test_enum_variant!(TestEnumCamelCase, (transform = "camelCase"));
+
+#[test]
+fn can_deserialize_camel_case() {
+    assert_eq!(
+        serde_json::from_str::<TestEnumCamelCase>("\"variant\"").unwrap(),
+        TestEnumCamelCase::Variant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumCamelCase>("\"exampleVariant\"").unwrap(),
+        TestEnumCamelCase::ExampleVariant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumCamelCase>("\"example2\"").unwrap(),
+        TestEnumCamelCase::Example2
+    );
+}
+
 test_enum_variant!(TestEnumSnakeCase, (transform = "snake_case"));
 
 #[test]
@@ -170,6 +210,126 @@ fn can_serialize_screaming_kebab_case() {
     );
 }
 
+test_enum_variant!(TestEnumTrainCase, (transform = "Train-Case"));
+
+#[test]
+fn can_deserialize_train_case() {
+    assert_eq!(
+        serde_json::from_str::<TestEnumTrainCase>("\"Variant\"").unwrap(),
+        TestEnumTrainCase::Variant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumTrainCase>("\"Example-Variant\"").unwrap(),
+        TestEnumTrainCase::ExampleVariant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumTrainCase>("\"Example2\"").unwrap(),
+        TestEnumTrainCase::Example2
+    );
+}
+
+test_enum_variant!(TestEnumCobolCase, (transform = "COBOL-CASE"));
+
+#[test]
+fn can_deserialize_cobol_case() {
+    assert_eq!(
+        serde_json::from_str::<TestEnumCobolCase>("\"VARIANT\"").unwrap(),
+        TestEnumCobolCase::Variant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumCobolCase>("\"EXAMPLE-VARIANT\"").unwrap(),
+        TestEnumCobolCase::ExampleVariant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumCobolCase>("\"EXAMPLE2\"").unwrap(),
+        TestEnumCobolCase::Example2
+    );
+}
+
+test_enum_variant!(TestEnumDotCase, (transform = "dot.case"));
+
+#[test]
+fn can_deserialize_dot_case() {
+    assert_eq!(
+        serde_json::from_str::<TestEnumDotCase>("\"variant\"").unwrap(),
+        TestEnumDotCase::Variant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumDotCase>("\"example.variant\"").unwrap(),
+        TestEnumDotCase::ExampleVariant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumDotCase>("\"example2\"").unwrap(),
+        TestEnumDotCase::Example2
+    );
+}
+
+test_enum_variant!(TestEnumTitleCase, (transform = "Title Case"));
+
+#[test]
+fn can_deserialize_title_case() {
+    assert_eq!(
+        serde_json::from_str::<TestEnumTitleCase>("\"Variant\"").unwrap(),
+        TestEnumTitleCase::Variant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumTitleCase>("\"Example Variant\"").unwrap(),
+        TestEnumTitleCase::ExampleVariant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumTitleCase>("\"Example2\"").unwrap(),
+        TestEnumTitleCase::Example2
+    );
+}
+
+test_enum_variant!(TestEnumFlatCase, (transform = "flatcase"));
+
+#[test]
+fn can_deserialize_flatcase() {
+    assert_eq!(
+        serde_json::from_str::<TestEnumFlatCase>("\"variant\"").unwrap(),
+        TestEnumFlatCase::Variant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumFlatCase>("\"examplevariant\"").unwrap(),
+        TestEnumFlatCase::ExampleVariant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumFlatCase>("\"example2\"").unwrap(),
+        TestEnumFlatCase::Example2
+    );
+}
+
+test_enum_variant!(TestEnumUpperFlatCase, (transform = "UPPERFLATCASE"));
+
+#[test]
+fn can_deserialize_upper_flatcase() {
+    assert_eq!(
+        serde_json::from_str::<TestEnumUpperFlatCase>("\"VARIANT\"").unwrap(),
+        TestEnumUpperFlatCase::Variant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumUpperFlatCase>("\"EXAMPLEVARIANT\"").unwrap(),
+        TestEnumUpperFlatCase::ExampleVariant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumUpperFlatCase>("\"EXAMPLE2\"").unwrap(),
+        TestEnumUpperFlatCase::Example2
+    );
+}
+
 test_enum_variant!(
     TestEnumPrependedSnakeCase,
     (transform = "snake_case", prepend_enum_name)
@@ -201,3 +361,329 @@ fn can_serialize_prepended_snake_case() {
         TestEnumPrependedSnakeCase::Example2
     );
 }
+
+#[serde_enum_string(transform = "snake_case")]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TestEnumRenamed {
+    Variant,
+    #[enum_string(rename = "application/json")]
+    ExampleVariant,
+    Example2,
+}
+
+#[test]
+fn can_deserialize_renamed_variant() {
+    assert_eq!(
+        serde_json::from_str::<TestEnumRenamed>("\"variant\"").unwrap(),
+        TestEnumRenamed::Variant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumRenamed>("\"application/json\"").unwrap(),
+        TestEnumRenamed::ExampleVariant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumRenamed>("\"example2\"").unwrap(),
+        TestEnumRenamed::Example2
+    );
+
+    assert!(serde_json::from_str::<TestEnumRenamed>("\"example_variant\"").is_err());
+}
+
+#[serde_enum_string(transform = "snake_case")]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TestEnumAliased {
+    #[enum_string(alias = "old_variant")]
+    #[enum_string(alias = "older_variant")]
+    Variant,
+    ExampleVariant,
+}
+
+#[test]
+fn can_deserialize_aliased_variant() {
+    assert_eq!(
+        serde_json::from_str::<TestEnumAliased>("\"variant\"").unwrap(),
+        TestEnumAliased::Variant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumAliased>("\"old_variant\"").unwrap(),
+        TestEnumAliased::Variant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumAliased>("\"older_variant\"").unwrap(),
+        TestEnumAliased::Variant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumAliased>("\"example_variant\"").unwrap(),
+        TestEnumAliased::ExampleVariant
+    );
+
+    let err = serde_json::from_str::<TestEnumAliased>("\"bad_value\"")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("\"variant\", \"example_variant\""));
+    assert!(!err.contains("old_variant"));
+}
+
+#[serde_enum_string(transform = "snake_case")]
+#[derive(Debug, PartialEq, Clone)]
+enum TestEnumOther {
+    Variant,
+    ExampleVariant,
+    #[enum_string(other)]
+    Unknown(String),
+}
+
+#[test]
+fn can_deserialize_other_variant() {
+    assert_eq!(
+        serde_json::from_str::<TestEnumOther>("\"example_variant\"").unwrap(),
+        TestEnumOther::ExampleVariant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumOther>("\"NewValue\"").unwrap(),
+        TestEnumOther::Unknown("NewValue".to_owned())
+    );
+
+    let round_trip: TestEnumOther = serde_json::from_str(
+        &serde_json::to_string(&TestEnumOther::Unknown("new_value".to_owned())).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(round_trip, TestEnumOther::Unknown("new_value".to_owned()));
+}
+
+#[serde_enum_string(transform = "snake_case")]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TestEnumDefault {
+    Variant,
+    ExampleVariant,
+    #[enum_string(default)]
+    Unspecified,
+}
+
+#[test]
+fn can_deserialize_default_variant() {
+    assert_eq!(
+        serde_json::from_str::<TestEnumDefault>("\"variant\"").unwrap(),
+        TestEnumDefault::Variant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumDefault>("\"unspecified\"").unwrap(),
+        TestEnumDefault::Unspecified
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumDefault>("\"new_value\"").unwrap(),
+        TestEnumDefault::Unspecified
+    );
+}
+
+static UNKNOWN_COUNT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+fn count_unknown(value: &str) {
+    assert_eq!(value, "new_value");
+    UNKNOWN_COUNT.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+}
+
+#[serde_enum_string(transform = "snake_case", on_unknown = "count_unknown")]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TestEnumFallbackHook {
+    Variant,
+    #[enum_string(fallback)]
+    Unspecified,
+}
+
+#[test]
+fn calls_hook_on_fallback() {
+    assert_eq!(
+        serde_json::from_str::<TestEnumFallbackHook>("\"variant\"").unwrap(),
+        TestEnumFallbackHook::Variant
+    );
+    assert_eq!(UNKNOWN_COUNT.load(std::sync::atomic::Ordering::SeqCst), 0);
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumFallbackHook>("\"new_value\"").unwrap(),
+        TestEnumFallbackHook::Unspecified
+    );
+    assert_eq!(UNKNOWN_COUNT.load(std::sync::atomic::Ordering::SeqCst), 1);
+}
+
+test_enum_variant!(
+    TestEnumCaseInsensitive,
+    (transform = "snake_case", matching = "case_insensitive")
+);
+
+#[test]
+fn can_deserialize_case_insensitive() {
+    assert_eq!(
+        serde_json::from_str::<TestEnumCaseInsensitive>("\"Example_Variant\"").unwrap(),
+        TestEnumCaseInsensitive::ExampleVariant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumCaseInsensitive>("\"EXAMPLE2\"").unwrap(),
+        TestEnumCaseInsensitive::Example2
+    );
+
+    assert!(serde_json::from_str::<TestEnumCaseInsensitive>("\"example-variant\"").is_err());
+    assert!(serde_json::from_str::<TestEnumCaseInsensitive>("\" variant \"").is_err());
+}
+
+test_enum_variant!(
+    TestEnumLenient,
+    (transform = "snake_case", matching = "lenient")
+);
+
+#[test]
+fn can_deserialize_lenient() {
+    assert_eq!(
+        serde_json::from_str::<TestEnumLenient>("\"Example_Variant\"").unwrap(),
+        TestEnumLenient::ExampleVariant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumLenient>("\" example_variant \"").unwrap(),
+        TestEnumLenient::ExampleVariant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumLenient>("\"example-variant\"").unwrap(),
+        TestEnumLenient::ExampleVariant
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumLenient>("\"Example Variant\"").unwrap(),
+        TestEnumLenient::ExampleVariant
+    );
+
+    assert!(serde_json::from_str::<TestEnumLenient>("\"examplevariant\"").is_err());
+}
+
+#[serde_enum_string(repr = "u8")]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TestEnumRepr {
+    Variant,
+    ExampleVariant = 5,
+    Example2,
+}
+
+#[test]
+fn can_deserialize_repr() {
+    assert_eq!(
+        serde_json::from_str::<TestEnumRepr>("0").unwrap(),
+        TestEnumRepr::Variant
+    );
+    assert_eq!(
+        serde_json::from_str::<TestEnumRepr>("5").unwrap(),
+        TestEnumRepr::ExampleVariant
+    );
+    assert_eq!(
+        serde_json::from_str::<TestEnumRepr>("6").unwrap(),
+        TestEnumRepr::Example2
+    );
+
+    let err = serde_json::from_str::<TestEnumRepr>("1")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("one of: 0, 5, 6"));
+
+    // Out of range for u8
+    assert!(serde_json::from_str::<TestEnumRepr>("261").is_err());
+    assert!(serde_json::from_str::<TestEnumRepr>("-1").is_err());
+    assert!(serde_json::from_str::<TestEnumRepr>("\"Variant\"").is_err());
+}
+
+#[serde_enum_string(repr = "i16")]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TestEnumSignedRepr {
+    Variant = -300,
+    ExampleVariant,
+    #[enum_string(default)]
+    Unspecified = 1000,
+}
+
+#[test]
+fn can_deserialize_signed_repr() {
+    assert_eq!(
+        serde_json::from_str::<TestEnumSignedRepr>("-300").unwrap(),
+        TestEnumSignedRepr::Variant
+    );
+    assert_eq!(
+        serde_json::from_str::<TestEnumSignedRepr>("-299").unwrap(),
+        TestEnumSignedRepr::ExampleVariant
+    );
+    assert_eq!(
+        serde_json::from_str::<TestEnumSignedRepr>("1000").unwrap(),
+        TestEnumSignedRepr::Unspecified
+    );
+    assert_eq!(
+        serde_json::from_str::<TestEnumSignedRepr>("7").unwrap(),
+        TestEnumSignedRepr::Unspecified
+    );
+    assert_eq!(
+        serde_json::from_str::<TestEnumSignedRepr>("100000").unwrap(),
+        TestEnumSignedRepr::Unspecified
+    );
+}
+
+#[test]
+fn repr_round_trips_through_fixed_width_format() {
+    let bytes = bincode::serialize(&TestEnumRepr::ExampleVariant).unwrap();
+    assert_eq!(bytes, [5]);
+    assert_eq!(
+        bincode::deserialize::<TestEnumRepr>(&bytes).unwrap(),
+        TestEnumRepr::ExampleVariant
+    );
+
+    let bytes = bincode::serialize(&TestEnumSignedRepr::Variant).unwrap();
+    assert_eq!(bytes, (-300i16).to_le_bytes());
+    assert_eq!(
+        bincode::deserialize::<TestEnumSignedRepr>(&bytes).unwrap(),
+        TestEnumSignedRepr::Variant
+    );
+
+    let pair = (TestEnumRepr::Example2, TestEnumSignedRepr::ExampleVariant);
+    let bytes = bincode::serialize(&pair).unwrap();
+    assert_eq!(
+        bincode::deserialize::<(TestEnumRepr, TestEnumSignedRepr)>(&bytes).unwrap(),
+        pair
+    );
+}
+
+#[serde_enum_string(transform = "snake_case", repr = "u8", dual_input = "string")]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TestEnumDual {
+    Variant = 1,
+    ExampleVariant,
+}
+
+#[test]
+fn can_deserialize_dual_input() {
+    assert_eq!(
+        serde_json::from_str::<TestEnumDual>("\"variant\"").unwrap(),
+        TestEnumDual::Variant
+    );
+    assert_eq!(
+        serde_json::from_str::<TestEnumDual>("1").unwrap(),
+        TestEnumDual::Variant
+    );
+    assert_eq!(
+        serde_json::from_str::<TestEnumDual>("\"example_variant\"").unwrap(),
+        TestEnumDual::ExampleVariant
+    );
+    assert_eq!(
+        serde_json::from_str::<TestEnumDual>("2").unwrap(),
+        TestEnumDual::ExampleVariant
+    );
+
+    let err = serde_json::from_str::<TestEnumDual>("true")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("one of: \"variant\", \"example_variant\", or one of: 1, 2"));
+}
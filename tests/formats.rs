@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0+
+
+use serde::de::value::{
+    BorrowedBytesDeserializer, BorrowedStrDeserializer, BytesDeserializer, Error,
+    StringDeserializer,
+};
+use serde::Deserialize;
+use serde_json_helpers::serde_enum_string;
+
+#[serde_enum_string(transform = "snake_case")]
+#[derive(Debug, PartialEq)]
+enum TestEnum {
+    Variant,
+    ExampleVariant,
+}
+
+#[serde_enum_string(transform = "snake_case")]
+#[derive(Debug, PartialEq)]
+enum TestEnumOther {
+    Variant,
+    #[enum_string(other)]
+    Unknown(String),
+}
+
+#[test]
+fn accepts_every_kind_of_string() {
+    assert_eq!(
+        TestEnum::deserialize(BorrowedStrDeserializer::<Error>::new("variant")).unwrap(),
+        TestEnum::Variant
+    );
+    assert_eq!(
+        TestEnum::deserialize(StringDeserializer::<Error>::new("variant".to_string())).unwrap(),
+        TestEnum::Variant
+    );
+    assert_eq!(
+        TestEnum::deserialize(BytesDeserializer::<Error>::new(b"example_variant")).unwrap(),
+        TestEnum::ExampleVariant
+    );
+    assert_eq!(
+        TestEnum::deserialize(BorrowedBytesDeserializer::<Error>::new(b"example_variant")).unwrap(),
+        TestEnum::ExampleVariant
+    );
+}
+
+#[test]
+fn rejects_invalid_bytes() {
+    let err = TestEnum::deserialize(BytesDeserializer::<Error>::new(b"\xffvariant"))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("invalid value: byte array"));
+
+    assert!(TestEnum::deserialize(BytesDeserializer::<Error>::new(b"unknown")).is_err());
+}
+
+#[test]
+fn takes_ownership_of_unknown_strings() {
+    assert_eq!(
+        TestEnumOther::deserialize(StringDeserializer::<Error>::new("variant".to_string()))
+            .unwrap(),
+        TestEnumOther::Variant
+    );
+    assert_eq!(
+        TestEnumOther::deserialize(StringDeserializer::<Error>::new("new_value".to_string()))
+            .unwrap(),
+        TestEnumOther::Unknown("new_value".to_string())
+    );
+    assert_eq!(
+        TestEnumOther::deserialize(BytesDeserializer::<Error>::new(b"new_value")).unwrap(),
+        TestEnumOther::Unknown("new_value".to_string())
+    );
+}
+
+#[test]
+fn can_deserialize_from_json() {
+    let value = serde_json::json!("example_variant");
+    assert_eq!(
+        serde_json::from_value::<TestEnum>(value).unwrap(),
+        TestEnum::ExampleVariant
+    );
+
+    assert_eq!(
+        serde_json::from_reader::<_, TestEnum>(&b"\"variant\""[..]).unwrap(),
+        TestEnum::Variant
+    );
+
+    // Escapes mean the string can't be borrowed from the input
+    assert_eq!(
+        serde_json::from_str::<TestEnum>("\"\\u0076ariant\"").unwrap(),
+        TestEnum::Variant
+    );
+
+    let value = serde_json::json!("new_value");
+    assert_eq!(
+        serde_json::from_value::<TestEnumOther>(value).unwrap(),
+        TestEnumOther::Unknown("new_value".to_string())
+    );
+}
+
+#[test]
+fn can_deserialize_from_cbor() {
+    // Text string
+    assert_eq!(
+        serde_cbor::from_slice::<TestEnum>(b"\x67variant").unwrap(),
+        TestEnum::Variant
+    );
+    // Byte string
+    assert_eq!(
+        serde_cbor::from_slice::<TestEnum>(b"\x47variant").unwrap(),
+        TestEnum::Variant
+    );
+    assert!(serde_cbor::from_slice::<TestEnum>(b"\x42\xff\xfe").is_err());
+}
+
+#[test]
+fn can_deserialize_from_messagepack() {
+    // str
+    assert_eq!(
+        rmp_serde::from_slice::<TestEnum>(b"\xa7variant").unwrap(),
+        TestEnum::Variant
+    );
+    // bin
+    assert_eq!(
+        rmp_serde::from_slice::<TestEnum>(b"\xc4\x07variant").unwrap(),
+        TestEnum::Variant
+    );
+    assert!(rmp_serde::from_slice::<TestEnum>(b"\xc4\x02\xff\xfe").is_err());
+
+    assert_eq!(
+        rmp_serde::from_slice::<TestEnum>(&rmp_serde::to_vec(&TestEnum::ExampleVariant).unwrap())
+            .unwrap(),
+        TestEnum::ExampleVariant
+    );
+}
+
+static UNKNOWN_COUNT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+fn count_unknown(value: &str) {
+    assert_eq!(value, "New_Value");
+    UNKNOWN_COUNT.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+}
+
+#[serde_enum_string(
+    transform = "snake_case",
+    matching = "case_insensitive",
+    on_unknown = "count_unknown"
+)]
+#[derive(Debug, PartialEq)]
+enum TestEnumOtherHook {
+    ExampleVariant,
+    #[enum_string(other)]
+    Unknown(String),
+}
+
+#[test]
+fn matches_owned_strings_like_borrowed_ones() {
+    assert_eq!(
+        TestEnumOtherHook::deserialize(StringDeserializer::<Error>::new(
+            "Example_Variant".to_string()
+        ))
+        .unwrap(),
+        TestEnumOtherHook::ExampleVariant
+    );
+    assert_eq!(UNKNOWN_COUNT.load(std::sync::atomic::Ordering::SeqCst), 0);
+
+    assert_eq!(
+        TestEnumOtherHook::deserialize(StringDeserializer::<Error>::new("New_Value".to_string()))
+            .unwrap(),
+        TestEnumOtherHook::Unknown("New_Value".to_string())
+    );
+    assert_eq!(UNKNOWN_COUNT.load(std::sync::atomic::Ordering::SeqCst), 1);
+}
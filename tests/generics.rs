@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0+
+
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+use serde_json_helpers::serde_enum_string;
+
+#[serde_enum_string(transform = "snake_case", from_str, variants)]
+#[derive(Debug, PartialEq, Clone)]
+enum TestEnumMarker<T: Clone> {
+    Variant,
+    ExampleVariant,
+    #[enum_string(skip)]
+    Marker(PhantomData<T>),
+}
+
+#[test]
+fn can_round_trip_generic_enum() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumMarker::<u8>::ExampleVariant).unwrap(),
+        serde_json::to_string("example_variant").unwrap()
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumMarker<u8>>("\"variant\"").unwrap(),
+        TestEnumMarker::Variant
+    );
+
+    assert_eq!(
+        "example_variant".parse::<TestEnumMarker<u8>>().unwrap(),
+        TestEnumMarker::ExampleVariant
+    );
+    assert_eq!(
+        TestEnumMarker::<u8>::VARIANTS,
+        &[TestEnumMarker::Variant, TestEnumMarker::ExampleVariant]
+    );
+}
+
+#[test]
+fn cannot_serialize_skipped_variant() {
+    assert!(serde_json::to_string(&TestEnumMarker::<u8>::Marker(PhantomData)).is_err());
+    assert!(serde_json::from_str::<TestEnumMarker<u8>>("\"marker\"").is_err());
+}
+
+#[serde_enum_string(transform = "snake_case")]
+#[derive(Debug, PartialEq, Clone)]
+enum TestEnumLifetime<'a> {
+    Variant,
+    #[enum_string(other)]
+    Unknown(Cow<'a, str>),
+}
+
+#[test]
+fn can_round_trip_lifetime_enum() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumLifetime::Unknown(Cow::Borrowed("new_value"))).unwrap(),
+        serde_json::to_string("new_value").unwrap()
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumLifetime>("\"variant\"").unwrap(),
+        TestEnumLifetime::Variant
+    );
+    assert_eq!(
+        serde_json::from_str::<TestEnumLifetime>("\"new_value\"").unwrap(),
+        TestEnumLifetime::Unknown(Cow::Borrowed("new_value"))
+    );
+}
+
+#[serde_enum_string]
+#[derive(Debug, PartialEq)]
+enum TestEnumWhere<T>
+where
+    T: Default,
+{
+    Variant,
+    #[enum_string(skip)]
+    Marker(PhantomData<T>),
+}
+
+#[test]
+fn supports_where_clauses() {
+    assert_eq!(
+        serde_json::from_str::<TestEnumWhere<String>>("\"Variant\"").unwrap(),
+        TestEnumWhere::Variant
+    );
+
+    assert!(serde_json::to_string(&TestEnumWhere::<String>::Marker(PhantomData)).is_err());
+}
@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0+
+
+mod util;
+
+use serde_json_helpers::serde_enum_string;
+
+mod reexport {
+    pub use ::serde as renamed_serde;
+}
+
+// Names the generated code must not pick up
+#[allow(dead_code)]
+struct TestEnumStrVisitor;
+#[allow(dead_code)]
+type Result<T> = ::std::result::Result<T, ()>;
+#[allow(dead_code)]
+mod std {}
+#[allow(dead_code)]
+mod serde {}
+
+test_enum_variant!(
+    TestEnum,
+    (
+        transform = "snake_case",
+        display,
+        from_str,
+        as_str,
+        variants
+    )
+);
+
+#[test]
+fn does_not_clash_with_user_names() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnum::ExampleVariant).unwrap(),
+        "\"example_variant\""
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnum>("\"example_variant\"").unwrap(),
+        TestEnum::ExampleVariant
+    );
+
+    assert_eq!(
+        "example2".parse::<TestEnum>(),
+        Ok::<_, ParseTestEnumError>(TestEnum::Example2)
+    );
+}
+
+test_enum_variant!(
+    TestEnumRenamedCrate,
+    (transform = "snake_case", crate = "reexport::renamed_serde")
+);
+
+#[test]
+fn supports_renamed_serde() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumRenamedCrate::ExampleVariant).unwrap(),
+        "\"example_variant\""
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestEnumRenamedCrate>("\"example_variant\"").unwrap(),
+        TestEnumRenamedCrate::ExampleVariant
+    );
+}
+
+// Names the generated generic parameters must not shadow
+#[serde_enum_string(with_module = "d_str")]
+#[derive(Debug, PartialEq)]
+enum D {
+    Variant,
+}
+
+#[serde_enum_string(repr = "u8")]
+#[derive(Debug, PartialEq)]
+enum E {
+    Variant,
+}
+
+#[serde_enum_string(transform = "snake_case")]
+#[derive(Debug, PartialEq)]
+enum Generic<S, D> {
+    ExampleVariant,
+    #[enum_string(skip)]
+    Marker(::std::marker::PhantomData<(S, D)>),
+}
+
+#[test]
+fn does_not_clash_with_generic_parameters() {
+    assert_eq!(serde_json::to_string(&D::Variant).unwrap(), "\"Variant\"");
+    assert_eq!(
+        serde_json::from_str::<D>("\"Variant\"").unwrap(),
+        D::Variant
+    );
+
+    assert_eq!(serde_json::to_string(&E::Variant).unwrap(), "0");
+    assert_eq!(serde_json::from_str::<E>("0").unwrap(), E::Variant);
+
+    assert_eq!(
+        serde_json::to_string(&Generic::<u8, u16>::ExampleVariant).unwrap(),
+        "\"example_variant\""
+    );
+    assert_eq!(
+        serde_json::from_str::<Generic<u8, u16>>("\"example_variant\"").unwrap(),
+        Generic::ExampleVariant
+    );
+    assert!(
+        serde_json::to_string(&Generic::<u8, u16>::Marker(::std::marker::PhantomData)).is_err()
+    );
+}
@@ -0,0 +1,164 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0+
+
+mod util;
+
+use serde_json_helpers::serde_enum_string;
+
+/// Check that `value` serializes to `expected` and deserializes back again
+macro_rules! assert_round_trip {
+    ($enum_name:ident::$variant:ident, $expected:expr) => {
+        assert_eq!(
+            &*serde_json::to_string(&$enum_name::$variant).unwrap(),
+            serde_json::to_string($expected).unwrap()
+        );
+
+        assert_eq!(
+            serde_json::from_str::<$enum_name>(&serde_json::to_string($expected).unwrap()).unwrap(),
+            $enum_name::$variant
+        );
+    };
+}
+
+test_enum_variant!(TestEnumPrefix, (transform = "snake_case", prefix = "test."));
+
+#[test]
+fn can_use_prefix() {
+    assert_round_trip!(TestEnumPrefix::Variant, "test.variant");
+    assert_round_trip!(TestEnumPrefix::ExampleVariant, "test.example_variant");
+}
+
+test_enum_variant!(TestEnumSuffix, (transform = "kebab-case", suffix = "/v1"));
+
+#[test]
+fn can_use_suffix() {
+    assert_round_trip!(TestEnumSuffix::Variant, "variant/v1");
+    assert_round_trip!(TestEnumSuffix::ExampleVariant, "example-variant/v1");
+}
+
+test_enum_variant!(
+    TestEnumTransformedAffixes,
+    (transform = "snake_case", suffix = "Kind", transform_affixes)
+);
+
+#[test]
+fn can_transform_affixes() {
+    assert_round_trip!(TestEnumTransformedAffixes::Variant, "variant_kind");
+    assert_round_trip!(
+        TestEnumTransformedAffixes::ExampleVariant,
+        "example_variant_kind"
+    );
+}
+
+#[serde_enum_string(transform = "lowercase", name_format = "{enum}.{variant}")]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Color {
+    Red,
+    DarkBlue,
+}
+
+#[test]
+fn can_use_name_format() {
+    assert_round_trip!(Color::Red, "color.red");
+    assert_round_trip!(Color::DarkBlue, "color.darkblue");
+}
+
+#[serde_enum_string(transform = "SCREAMING_SNAKE_CASE", name_format = "{enum}::{variant}")]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TextColor {
+    Red,
+    DarkBlue,
+}
+
+#[test]
+fn transforms_name_format_placeholders_individually() {
+    assert_round_trip!(TextColor::Red, "TEXT_COLOR::RED");
+    assert_round_trip!(TextColor::DarkBlue, "TEXT_COLOR::DARK_BLUE");
+}
+
+#[serde_enum_string(
+    transform = "snake_case",
+    name_format = "{variant}{enum}",
+    transform_affixes
+)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum BackgroundColor {
+    Red,
+    DarkBlue,
+}
+
+#[test]
+fn can_transform_whole_name_format() {
+    assert_round_trip!(BackgroundColor::Red, "red_background_color");
+    assert_round_trip!(BackgroundColor::DarkBlue, "dark_blue_background_color");
+}
+
+test_enum_variant!(
+    TestEnumTransformedPrefix,
+    (
+        transform = "snake_case",
+        prefix = "Test.",
+        transform_affixes
+    )
+);
+
+#[test]
+fn keeps_punctuation_in_transformed_affixes() {
+    assert_round_trip!(TestEnumTransformedPrefix::Variant, "test.variant");
+    assert_round_trip!(
+        TestEnumTransformedPrefix::ExampleVariant,
+        "test.example_variant"
+    );
+}
+
+#[serde_enum_string(
+    transform = "snake_case",
+    name_format = "{enum}.{variant}",
+    transform_affixes
+)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum BorderColor {
+    Red,
+    DarkRed,
+}
+
+#[test]
+fn keeps_punctuation_in_transformed_name_format() {
+    assert_round_trip!(BorderColor::Red, "border_color.red");
+    assert_round_trip!(BorderColor::DarkRed, "border_color.dark_red");
+}
+
+#[serde_enum_string(
+    transform = "SCREAMING_SNAKE_CASE",
+    prefix = "Color::",
+    transform_affixes
+)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum FillColor {
+    Red,
+    DarkRed,
+}
+
+#[test]
+fn keeps_path_separators_in_transformed_affixes() {
+    assert_round_trip!(FillColor::Red, "COLOR::RED");
+    assert_round_trip!(FillColor::DarkRed, "COLOR::DARK_RED");
+}
+
+#[serde_enum_string(
+    transform = "snake_case",
+    prefix = "<",
+    name_format = "{{{variant}}}",
+    suffix = ">"
+)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Escaped {
+    Variant,
+    #[enum_string(rename = "verbatim")]
+    Renamed,
+}
+
+#[test]
+fn name_format_escapes_braces_and_skips_renamed() {
+    assert_round_trip!(Escaped::Variant, "<{variant}>");
+    assert_round_trip!(Escaped::Renamed, "verbatim");
+}
@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0+
+
+/// Declare the same `enum` twice, once with serde's derives and once with `serde_enum_string`,
+/// so that the two can be checked to behave identically.
+macro_rules! migrated_enum {
+    ($(#[$attr:meta])* enum $enum_name:ident { $($body:tt)* }) => {
+        mod with_serde {
+            use serde::{Deserialize, Serialize};
+
+            #[derive(Debug, PartialEq, Serialize, Deserialize)]
+            $(#[$attr])*
+            pub enum $enum_name { $($body)* }
+        }
+
+        mod with_macro {
+            use serde_json_helpers::serde_enum_string;
+
+            #[serde_enum_string]
+            #[derive(Debug, PartialEq)]
+            $(#[$attr])*
+            pub enum $enum_name { $($body)* }
+        }
+    };
+}
+
+migrated_enum! {
+    #[serde(rename_all = "SCREAMING-KEBAB-CASE")]
+    enum Status {
+        HTTPServer,
+        ExampleVariant,
+        Example2,
+        #[serde(rename = "renamed")]
+        Renamed,
+        #[serde(alias = "old-name", alias = "older")]
+        Aliased,
+        #[serde(skip)]
+        Skipped,
+        #[serde(other)]
+        Unknown,
+    }
+}
+
+macro_rules! assert_same_serialization {
+    ($($variant:ident),*) => {
+        $(
+            assert_eq!(
+                serde_json::to_string(&with_macro::Status::$variant).unwrap(),
+                serde_json::to_string(&with_serde::Status::$variant).unwrap()
+            );
+        )*
+    };
+}
+
+#[test]
+fn serializes_like_serde() {
+    assert_same_serialization!(HTTPServer, ExampleVariant, Example2, Renamed, Aliased);
+
+    assert_eq!(
+        serde_json::to_string(&with_macro::Status::HTTPServer).unwrap(),
+        "\"H-T-T-P-SERVER\""
+    );
+
+    assert!(serde_json::to_string(&with_serde::Status::Skipped).is_err());
+    assert!(serde_json::to_string(&with_macro::Status::Skipped).is_err());
+}
+
+#[test]
+fn deserializes_like_serde() {
+    let inputs = [
+        "\"H-T-T-P-SERVER\"",
+        "\"EXAMPLE-VARIANT\"",
+        "\"EXAMPLE2\"",
+        "\"renamed\"",
+        "\"ALIASED\"",
+        "\"old-name\"",
+        "\"older\"",
+        "\"SKIPPED\"",
+        "\"something-else\"",
+    ];
+
+    for input in &inputs {
+        let with_serde = serde_json::from_str::<with_serde::Status>(input).unwrap();
+        let with_macro = serde_json::from_str::<with_macro::Status>(input).unwrap();
+
+        assert_eq!(format!("{:?}", with_macro), format!("{:?}", with_serde));
+    }
+}
+
+#[derive(Debug, PartialEq, serde_json_helpers::SerdeEnumString)]
+#[serde(rename_all = "camelCase")]
+enum Derived {
+    ExampleVariant,
+    #[serde(rename = "two", alias = "2")]
+    Example2,
+}
+
+#[test]
+fn derive_honours_serde_attributes() {
+    assert_eq!(
+        serde_json::to_string(&Derived::ExampleVariant).unwrap(),
+        "\"exampleVariant\""
+    );
+
+    assert_eq!(
+        serde_json::from_str::<Derived>("\"2\"").unwrap(),
+        Derived::Example2
+    );
+}
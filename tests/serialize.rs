@@ -84,6 +84,46 @@ fn can_serialize_uppercase() {
     );
 }
 
+test_enum_variant!(TestEnumPascalCase, (transform = "PascalCase"));
+
+#[test]
+fn can_serialize_pascal_case() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumPascalCase::Variant).unwrap(),
+        serde_json::to_string("Variant").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumPascalCase::ExampleVariant).unwrap(),
+        serde_json::to_string("ExampleVariant").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumPascalCase::Example2).unwrap(),
+        serde_json::to_string("Example2").unwrap()
+    );
+}
+
+test_enum_variant!(TestEnumCamelCase, (transform = "camelCase"));
+
+#[test]
+fn can_serialize_camel_case() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumCamelCase::Variant).unwrap(),
+        serde_json::to_string("variant").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumCamelCase::ExampleVariant).unwrap(),
+        serde_json::to_string("exampleVariant").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumCamelCase::Example2).unwrap(),
+        serde_json::to_string("example2").unwrap()
+    );
+}
+
 test_enum_variant!(TestEnumSnakeCase, (transform = "snake_case"));
 
 #[test]
@@ -170,6 +210,126 @@ fn can_serialize_screaming_kebab_case() {
     );
 }
 
+test_enum_variant!(TestEnumTrainCase, (transform = "Train-Case"));
+
+#[test]
+fn can_serialize_train_case() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumTrainCase::Variant).unwrap(),
+        serde_json::to_string("Variant").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumTrainCase::ExampleVariant).unwrap(),
+        serde_json::to_string("Example-Variant").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumTrainCase::Example2).unwrap(),
+        serde_json::to_string("Example2").unwrap()
+    );
+}
+
+test_enum_variant!(TestEnumCobolCase, (transform = "COBOL-CASE"));
+
+#[test]
+fn can_serialize_cobol_case() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumCobolCase::Variant).unwrap(),
+        serde_json::to_string("VARIANT").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumCobolCase::ExampleVariant).unwrap(),
+        serde_json::to_string("EXAMPLE-VARIANT").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumCobolCase::Example2).unwrap(),
+        serde_json::to_string("EXAMPLE2").unwrap()
+    );
+}
+
+test_enum_variant!(TestEnumDotCase, (transform = "dot.case"));
+
+#[test]
+fn can_serialize_dot_case() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumDotCase::Variant).unwrap(),
+        serde_json::to_string("variant").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumDotCase::ExampleVariant).unwrap(),
+        serde_json::to_string("example.variant").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumDotCase::Example2).unwrap(),
+        serde_json::to_string("example2").unwrap()
+    );
+}
+
+test_enum_variant!(TestEnumTitleCase, (transform = "Title Case"));
+
+#[test]
+fn can_serialize_title_case() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumTitleCase::Variant).unwrap(),
+        serde_json::to_string("Variant").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumTitleCase::ExampleVariant).unwrap(),
+        serde_json::to_string("Example Variant").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumTitleCase::Example2).unwrap(),
+        serde_json::to_string("Example2").unwrap()
+    );
+}
+
+test_enum_variant!(TestEnumFlatCase, (transform = "flatcase"));
+
+#[test]
+fn can_serialize_flatcase() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumFlatCase::Variant).unwrap(),
+        serde_json::to_string("variant").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumFlatCase::ExampleVariant).unwrap(),
+        serde_json::to_string("examplevariant").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumFlatCase::Example2).unwrap(),
+        serde_json::to_string("example2").unwrap()
+    );
+}
+
+test_enum_variant!(TestEnumUpperFlatCase, (transform = "UPPERFLATCASE"));
+
+#[test]
+fn can_serialize_upper_flatcase() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumUpperFlatCase::Variant).unwrap(),
+        serde_json::to_string("VARIANT").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumUpperFlatCase::ExampleVariant).unwrap(),
+        serde_json::to_string("EXAMPLEVARIANT").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumUpperFlatCase::Example2).unwrap(),
+        serde_json::to_string("EXAMPLE2").unwrap()
+    );
+}
+
 test_enum_variant!(
     TestEnumPrependedSnakeCase,
     (transform = "snake_case", prepend_enum_name)
@@ -192,3 +352,156 @@ fn can_serialize_prepended_snake_case() {
         serde_json::to_string("test_enum_prepended_snake_case_example2").unwrap()
     );
 }
+
+#[serde_enum_string(transform = "snake_case")]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TestEnumRenamed {
+    Variant,
+    #[enum_string(rename = "application/json")]
+    ExampleVariant,
+    Example2,
+}
+
+#[test]
+fn can_serialize_renamed_variant() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumRenamed::Variant).unwrap(),
+        serde_json::to_string("variant").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumRenamed::ExampleVariant).unwrap(),
+        serde_json::to_string("application/json").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumRenamed::Example2).unwrap(),
+        serde_json::to_string("example2").unwrap()
+    );
+}
+
+#[serde_enum_string(transform = "snake_case")]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TestEnumAliased {
+    #[enum_string(alias = "old_variant", alias = "older_variant")]
+    Variant,
+    ExampleVariant,
+}
+
+#[test]
+fn can_serialize_aliased_variant() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumAliased::Variant).unwrap(),
+        serde_json::to_string("variant").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumAliased::ExampleVariant).unwrap(),
+        serde_json::to_string("example_variant").unwrap()
+    );
+}
+
+#[serde_enum_string(transform = "snake_case")]
+#[derive(Debug, PartialEq, Clone)]
+enum TestEnumOther {
+    Variant,
+    ExampleVariant,
+    #[enum_string(other)]
+    Unknown(String),
+}
+
+#[test]
+fn can_serialize_other_variant() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumOther::Variant).unwrap(),
+        serde_json::to_string("variant").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumOther::Unknown("NewValue".to_owned())).unwrap(),
+        serde_json::to_string("NewValue").unwrap()
+    );
+}
+
+test_enum_variant!(
+    TestEnumLenient,
+    (transform = "kebab-case", matching = "lenient")
+);
+
+#[test]
+fn can_serialize_lenient_as_canonical() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumLenient::ExampleVariant).unwrap(),
+        serde_json::to_string("example-variant").unwrap()
+    );
+}
+
+#[serde_enum_string(repr = "u8")]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TestEnumRepr {
+    Variant,
+    ExampleVariant = 5,
+    Example2,
+}
+
+#[test]
+fn can_serialize_repr() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumRepr::Variant).unwrap(),
+        "0"
+    );
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumRepr::ExampleVariant).unwrap(),
+        "5"
+    );
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumRepr::Example2).unwrap(),
+        "6"
+    );
+}
+
+#[serde_enum_string(repr = "i16")]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TestEnumSignedRepr {
+    Variant = -300,
+    ExampleVariant,
+}
+
+#[test]
+fn can_serialize_signed_repr() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumSignedRepr::Variant).unwrap(),
+        "-300"
+    );
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumSignedRepr::ExampleVariant).unwrap(),
+        "-299"
+    );
+}
+
+#[serde_enum_string(transform = "snake_case", repr = "u8", dual_input = "string")]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TestEnumDualString {
+    Variant = 1,
+    ExampleVariant,
+}
+
+#[serde_enum_string(transform = "snake_case", repr = "u8", dual_input = "repr")]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TestEnumDualRepr {
+    Variant = 1,
+    ExampleVariant,
+}
+
+#[test]
+fn can_serialize_dual_input_canonical_form() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumDualString::ExampleVariant).unwrap(),
+        serde_json::to_string("example_variant").unwrap()
+    );
+
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumDualRepr::ExampleVariant).unwrap(),
+        "2"
+    );
+}
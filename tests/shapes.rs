@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0+
+
+// The generated code should be warning free whatever the shape of the `enum`
+#![deny(warnings)]
+
+use serde_json_helpers::{serde_enum_string, SerdeEnumString};
+use std::str::FromStr;
+
+#[serde_enum_string(from_str, display, as_str, variants)]
+#[derive(Debug, PartialEq)]
+enum Empty {}
+
+#[test]
+fn rejects_everything_for_empty_enum() {
+    let err = serde_json::from_str::<Empty>("\"variant\"")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("expected nothing, as Empty has no variants"));
+
+    assert_eq!(
+        Empty::from_str("variant").unwrap_err().to_string(),
+        "invalid value \"variant\", expected nothing, as Empty has no variants"
+    );
+
+    assert!(Empty::VARIANTS.is_empty());
+    assert!(Empty::NAMES.is_empty());
+}
+
+#[serde_enum_string(repr = "u8", dual_input = "string", with_module = "empty_repr")]
+#[derive(Debug, PartialEq)]
+enum EmptyRepr {}
+
+#[test]
+fn rejects_everything_for_empty_repr_enum() {
+    let err = serde_json::from_str::<EmptyRepr>("0")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("expected nothing, as EmptyRepr has no variants"));
+    assert!(serde_json::from_str::<EmptyRepr>("\"variant\"").is_err());
+}
+
+#[derive(Debug, PartialEq, SerdeEnumString)]
+#[enum_string(display, as_str)]
+enum EmptyDerived {
+    #[cfg(not(test))]
+    Variant,
+}
+
+#[test]
+fn rejects_everything_for_enum_with_no_compiled_variants() {
+    assert!(serde_json::from_str::<EmptyDerived>("\"Variant\"").is_err());
+}
+
+#[serde_enum_string(display, as_str)]
+#[derive(Debug, PartialEq)]
+enum EmptyConditional {
+    #[cfg(not(test))]
+    Variant,
+}
+
+#[test]
+fn supports_enum_which_may_be_empty() {
+    assert!(serde_json::from_str::<EmptyConditional>("\"Variant\"").is_err());
+}
+
+#[serde_enum_string(transform = "snake_case", from_str, display, as_str, variants)]
+#[derive(Debug, PartialEq)]
+enum Single {
+    OnlyVariant,
+}
+
+#[test]
+fn supports_single_variant_enum() {
+    assert_eq!(
+        serde_json::to_string(&Single::OnlyVariant).unwrap(),
+        "\"only_variant\""
+    );
+    assert_eq!(
+        serde_json::from_str::<Single>("\"only_variant\"").unwrap(),
+        Single::OnlyVariant
+    );
+    assert_eq!(Single::OnlyVariant.to_string(), "only_variant");
+    assert_eq!(Single::OnlyVariant.as_str(), "only_variant");
+    assert_eq!(Single::VARIANTS, &[Single::OnlyVariant]);
+    assert!(Single::from_str("other").is_err());
+}
+
+#[serde_enum_string(transform = "snake_case")]
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
+enum Versioned {
+    First,
+    Second,
+}
+
+#[serde_enum_string(transform = "snake_case")]
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
+enum VersionedWithDefault {
+    First,
+    #[enum_string(default)]
+    Unrecognised,
+}
+
+#[test]
+fn supports_non_exhaustive_enum() {
+    assert_eq!(
+        serde_json::from_str::<Versioned>("\"second\"").unwrap(),
+        Versioned::Second
+    );
+    assert!(serde_json::from_str::<Versioned>("\"third\"").is_err());
+
+    assert_eq!(
+        serde_json::from_str::<VersionedWithDefault>("\"third\"").unwrap(),
+        VersionedWithDefault::Unrecognised
+    );
+    assert_eq!(
+        serde_json::to_string(&VersionedWithDefault::First).unwrap(),
+        "\"first\""
+    );
+}
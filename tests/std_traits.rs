@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0+
+
+mod util;
+
+use serde_json_helpers::serde_enum_string;
+
+test_enum_variant!(
+    TestEnum,
+    (transform = "snake_case", display, from_str, as_str)
+);
+
+#[test]
+fn as_str_matches_serialized() {
+    for variant in &[
+        TestEnum::Variant,
+        TestEnum::ExampleVariant,
+        TestEnum::Example2,
+    ] {
+        assert_eq!(
+            serde_json::to_string(variant).unwrap(),
+            serde_json::to_string(variant.as_str()).unwrap()
+        );
+    }
+
+    let name: &'static str = TestEnum::ExampleVariant.as_str();
+    assert_eq!(name, "example_variant");
+}
+
+#[test]
+fn display_matches_serialized() {
+    for variant in &[
+        TestEnum::Variant,
+        TestEnum::ExampleVariant,
+        TestEnum::Example2,
+    ] {
+        assert_eq!(
+            serde_json::to_string(variant).unwrap(),
+            serde_json::to_string(&variant.to_string()).unwrap()
+        );
+    }
+}
+
+#[test]
+fn can_parse_from_str() {
+    assert_eq!("variant".parse::<TestEnum>().unwrap(), TestEnum::Variant);
+    assert_eq!(
+        "example_variant".parse::<TestEnum>().unwrap(),
+        TestEnum::ExampleVariant
+    );
+    assert_eq!("example2".parse::<TestEnum>().unwrap(), TestEnum::Example2);
+}
+
+#[test]
+fn parse_error_lists_accepted_values() {
+    let err = "bad_value".parse::<TestEnum>().unwrap_err();
+
+    assert_eq!(err.value(), "bad_value");
+    assert_eq!(
+        err.to_string(),
+        "invalid value \"bad_value\", expected one of: \"variant\", \"example_variant\", \"example2\""
+    );
+
+    let _: &dyn std::error::Error = &err;
+}
+
+#[serde_enum_string(transform = "kebab-case", matching = "lenient", from_str, as_str)]
+#[derive(Debug, PartialEq, Clone)]
+enum TestEnumOther {
+    ExampleVariant,
+    #[enum_string(other)]
+    Unknown(String),
+}
+
+#[test]
+fn from_str_follows_deserialize_rules() {
+    assert_eq!(
+        "Example_Variant".parse::<TestEnumOther>().unwrap(),
+        TestEnumOther::ExampleVariant
+    );
+    assert_eq!(
+        "new-value".parse::<TestEnumOther>().unwrap(),
+        TestEnumOther::Unknown("new-value".to_owned())
+    );
+    assert_eq!(
+        TestEnumOther::Unknown("new-value".to_owned()).as_str(),
+        "new-value"
+    );
+}
+
+#[serde_enum_string(transform = "snake_case", repr = "u8", display, as_str)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TestEnumRepr {
+    Variant = 1,
+    ExampleVariant = 2,
+}
+
+#[test]
+fn display_and_as_str_use_strings_with_repr() {
+    assert_eq!(
+        &*serde_json::to_string(&TestEnumRepr::ExampleVariant).unwrap(),
+        "2"
+    );
+
+    assert_eq!(TestEnumRepr::ExampleVariant.as_str(), "example_variant");
+    assert_eq!(TestEnumRepr::ExampleVariant.to_string(), "example_variant");
+}
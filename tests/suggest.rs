@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0+
+
+use serde_json_helpers::serde_enum_string;
+
+#[serde_enum_string(transform = "snake_case", suggest)]
+#[derive(Debug, PartialEq)]
+enum Transform {
+    SnakeCase,
+    KebabCase,
+    CamelCase,
+    #[cfg(not(test))]
+    SnakeCaseDisabled,
+    #[enum_string(rename = "upper")]
+    Uppercase,
+    #[enum_string(rename = "lower")]
+    Lowercase,
+}
+
+fn error_for(input: &str) -> String {
+    serde_json::from_str::<Transform>(&serde_json::to_string(input).unwrap())
+        .unwrap_err()
+        .to_string()
+}
+
+#[test]
+fn suggests_closest_value() {
+    assert_eq!(
+        error_for("snak_case"),
+        "invalid value: string \"snak_case\", expected one of: \"snake_case\", \"kebab_case\", \
+         \"camel_case\", \"upper\", \"lower\"; did you mean \"snake_case\"? at line 1 column 11"
+    );
+
+    assert!(error_for("Kebab-Case").contains("did you mean \"kebab_case\"?"));
+}
+
+#[test]
+fn suggests_every_equally_close_value() {
+    assert!(error_for("uoxer").contains("did you mean \"upper\" or \"lower\"?"));
+}
+
+#[test]
+fn omits_suggestion_for_distant_values() {
+    let err = error_for("something_else");
+
+    assert!(err.contains("expected one of:"));
+    assert!(!err.contains("did you mean"));
+}
+
+#[serde_enum_string(
+    transform = "snake_case",
+    unit_variant,
+    suggest,
+    with_module = "level_str"
+)]
+#[derive(Debug, PartialEq)]
+enum Level {
+    Low,
+    High,
+}
+
+#[test]
+fn suggests_for_unit_variants() {
+    let err = ron::from_str::<Level>("hihg").unwrap_err().to_string();
+
+    assert!(err.contains("did you mean \"high\"?"));
+}
+
+#[serde_enum_string(transform = "snake_case")]
+#[derive(Debug, PartialEq)]
+enum Unsuggested {
+    SnakeCase,
+}
+
+#[test]
+fn only_suggests_when_asked() {
+    let err = serde_json::from_str::<Unsuggested>("\"snak_case\"")
+        .unwrap_err()
+        .to_string();
+
+    assert!(!err.contains("did you mean"));
+}
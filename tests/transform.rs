@@ -0,0 +1,219 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0+
+
+use serde_json_helpers::serde_enum_string;
+
+/// Declare an enum with the given options and check that every variant serializes to, and
+/// deserializes from, the expected string.
+macro_rules! test_transform {
+    ($test_name:ident, $enum_name:ident, $attrs:tt, { $($variant:ident => $expected:expr,)* }) => {
+        #[serde_enum_string$attrs]
+        #[derive(Debug, PartialEq, Clone, Copy)]
+        enum $enum_name {
+            $($variant,)*
+        }
+
+        #[test]
+        fn $test_name() {
+            $(
+                assert_eq!(
+                    serde_json::to_value(&$enum_name::$variant).unwrap(),
+                    $expected,
+                    "serializing {}::{}",
+                    stringify!($enum_name),
+                    stringify!($variant)
+                );
+
+                assert_eq!(
+                    serde_json::from_value::<$enum_name>($expected.into()).unwrap(),
+                    $enum_name::$variant
+                );
+            )*
+        }
+    };
+}
+
+test_transform!(lowercase, TestEnumLowercase, (transform = "lowercase"), {
+    Variant => "variant",
+    ExampleVariant => "examplevariant",
+    Example2 => "example2",
+    HTTPServer => "httpserver",
+    HTTP2Server => "http2server",
+    XMLHttpRequest => "xmlhttprequest",
+    ApiURL => "apiurl",
+    Utf8Encoding => "utf8encoding",
+    Vec2D => "vec2d",
+    A => "a",
+    ÉtatCivil => "étatcivil",
+    日本Language => "日本language",
+});
+
+test_transform!(uppercase, TestEnumUppercase, (transform = "UPPERCASE"), {
+    Variant => "VARIANT",
+    ExampleVariant => "EXAMPLEVARIANT",
+    Example2 => "EXAMPLE2",
+    HTTPServer => "HTTPSERVER",
+    HTTP2Server => "HTTP2SERVER",
+    XMLHttpRequest => "XMLHTTPREQUEST",
+    ApiURL => "APIURL",
+    Utf8Encoding => "UTF8ENCODING",
+    Vec2D => "VEC2D",
+    A => "A",
+    ÉtatCivil => "ÉTATCIVIL",
+    日本Language => "日本LANGUAGE",
+});
+
+test_transform!(pascal_case, TestEnumPascalCase, (transform = "PascalCase"), {
+    Variant => "Variant",
+    ExampleVariant => "ExampleVariant",
+    Example2 => "Example2",
+    HTTPServer => "HttpServer",
+    HTTP2Server => "Http2Server",
+    XMLHttpRequest => "XmlHttpRequest",
+    ApiURL => "ApiUrl",
+    Utf8Encoding => "Utf8Encoding",
+    Vec2D => "Vec2D",
+    A => "A",
+    ÉtatCivil => "ÉtatCivil",
+    日本Language => "日本Language",
+});
+
+test_transform!(camel_case, TestEnumCamelCase, (transform = "camelCase"), {
+    Variant => "variant",
+    ExampleVariant => "exampleVariant",
+    Example2 => "example2",
+    HTTPServer => "httpServer",
+    HTTP2Server => "http2Server",
+    XMLHttpRequest => "xmlHttpRequest",
+    ApiURL => "apiUrl",
+    Utf8Encoding => "utf8Encoding",
+    Vec2D => "vec2D",
+    A => "a",
+    ÉtatCivil => "étatCivil",
+    日本Language => "日本Language",
+});
+
+test_transform!(snake_case, TestEnumSnakeCase, (transform = "snake_case"), {
+    Variant => "variant",
+    ExampleVariant => "example_variant",
+    Example2 => "example2",
+    HTTPServer => "http_server",
+    HTTP2Server => "http2_server",
+    XMLHttpRequest => "xml_http_request",
+    ApiURL => "api_url",
+    Utf8Encoding => "utf8_encoding",
+    Vec2D => "vec2_d",
+    A => "a",
+    ÉtatCivil => "état_civil",
+    日本Language => "日本_language",
+});
+
+test_transform!(
+    screaming_snake_case,
+    TestEnumScreamingSnakeCase,
+    (transform = "SCREAMING_SNAKE_CASE"),
+    {
+        Variant => "VARIANT",
+        ExampleVariant => "EXAMPLE_VARIANT",
+        Example2 => "EXAMPLE2",
+        HTTPServer => "HTTP_SERVER",
+        HTTP2Server => "HTTP2_SERVER",
+        XMLHttpRequest => "XML_HTTP_REQUEST",
+        ApiURL => "API_URL",
+        Utf8Encoding => "UTF8_ENCODING",
+        Vec2D => "VEC2_D",
+        A => "A",
+        ÉtatCivil => "ÉTAT_CIVIL",
+        日本Language => "日本_LANGUAGE",
+    }
+);
+
+test_transform!(kebab_case, TestEnumKebabCase, (transform = "kebab-case"), {
+    Variant => "variant",
+    ExampleVariant => "example-variant",
+    Example2 => "example2",
+    HTTPServer => "http-server",
+    HTTP2Server => "http2-server",
+    XMLHttpRequest => "xml-http-request",
+    ApiURL => "api-url",
+    Utf8Encoding => "utf8-encoding",
+    Vec2D => "vec2-d",
+    A => "a",
+    ÉtatCivil => "état-civil",
+    日本Language => "日本-language",
+});
+
+test_transform!(
+    screaming_kebab_case,
+    TestEnumScreamingKebabCase,
+    (transform = "SCREAMING-KEBAB-CASE"),
+    {
+        Variant => "VARIANT",
+        ExampleVariant => "EXAMPLE-VARIANT",
+        Example2 => "EXAMPLE2",
+        HTTPServer => "HTTP-SERVER",
+        HTTP2Server => "HTTP2-SERVER",
+        XMLHttpRequest => "XML-HTTP-REQUEST",
+        ApiURL => "API-URL",
+        Utf8Encoding => "UTF8-ENCODING",
+        Vec2D => "VEC2-D",
+        A => "A",
+        ÉtatCivil => "ÉTAT-CIVIL",
+        日本Language => "日本-LANGUAGE",
+    }
+);
+
+test_transform!(
+    snake_case_split_digits,
+    TestEnumSnakeCaseSplitDigits,
+    (transform = "snake_case", split_digits),
+    {
+        Variant => "variant",
+        Example2 => "example_2",
+        HTTP2Server => "http_2_server",
+        Utf8Encoding => "utf_8_encoding",
+        Vec2D => "vec_2_d",
+        Ipv4Address => "ipv_4_address",
+        Example22 => "example_22",
+    }
+);
+
+test_transform!(
+    camel_case_split_digits,
+    TestEnumCamelCaseSplitDigits,
+    (transform = "camelCase", split_digits),
+    {
+        Variant => "variant",
+        Example2 => "example2",
+        HTTP2Server => "http2Server",
+        Utf8Encoding => "utf8Encoding",
+        Vec2D => "vec2D",
+    }
+);
+
+test_transform!(
+    screaming_kebab_case_split_digits,
+    TestEnumScreamingKebabCaseSplitDigits,
+    (transform = "SCREAMING-KEBAB-CASE", split_digits),
+    {
+        Example2 => "EXAMPLE-2",
+        HTTP2Server => "HTTP-2-SERVER",
+        Vec2D => "VEC-2-D",
+    }
+);
+
+test_transform!(
+    snake_case_prepended_acronym,
+    HTTPMethod,
+    (transform = "snake_case", prepend_enum_name),
+    {
+        Get => "http_method_get",
+        PostJSON => "http_method_post_json",
+    }
+);
+
+test_transform!(train_case, TestEnumTrainCase, (transform = "Train-Case"), {
+    ContentType => "Content-Type",
+    XForwardedFor => "X-Forwarded-For",
+    HTTP2Settings => "Http2-Settings",
+    ÉtatCivil => "État-Civil",
+});
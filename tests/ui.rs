@@ -0,0 +1,7 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0+
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,8 @@
+use serde_json_helpers::serde_enum_string;
+
+#[serde_enum_string(transform = "snek_case")]
+enum BadTransform {
+    Variant,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use serde_json_helpers::serde_enum_string;
+
+#[serde_enum_string(transform = "snake_case")]
+enum CfgAttrHelper {
+    #[cfg_attr(test, enum_string(rename = "renamed"))]
+    Variant,
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+use serde_json_helpers::serde_enum_string;
+
+#[serde_enum_string(transform = "lowercase")]
+enum Plain {
+    FooBar,
+    Foobar,
+}
+
+#[serde_enum_string(transform = "snake_case")]
+enum Alias {
+    Red,
+    #[enum_string(alias = "red")]
+    Crimson,
+}
+
+#[serde_enum_string(transform = "snake_case", prefix = "dark_")]
+enum Prefix {
+    Red,
+    #[enum_string(rename = "dark_red")]
+    DarkRed,
+}
+
+#[serde_enum_string(transform = "snake_case", name_format = "{enum}.{variant}")]
+enum Color {
+    Red,
+    #[enum_string(rename = "color.red")]
+    Crimson,
+}
+
+fn main() {}
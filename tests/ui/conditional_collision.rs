@@ -0,0 +1,13 @@
+use serde_json_helpers::serde_enum_string;
+
+// Differing conditions which are nonetheless both enabled
+#[serde_enum_string(transform = "snake_case")]
+enum Conditional {
+    #[cfg(all())]
+    Native,
+    #[cfg(not(any()))]
+    #[enum_string(rename = "native")]
+    Fallback,
+}
+
+fn main() {}
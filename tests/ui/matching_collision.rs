@@ -0,0 +1,16 @@
+use serde_json_helpers::serde_enum_string;
+
+#[serde_enum_string(matching = "case_insensitive")]
+enum CaseInsensitive {
+    FooBar,
+    Foobar,
+}
+
+#[serde_enum_string(transform = "snake_case", matching = "lenient")]
+enum Lenient {
+    FooBar,
+    #[enum_string(rename = "foo-bar")]
+    Other,
+}
+
+fn main() {}
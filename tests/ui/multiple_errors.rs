@@ -0,0 +1,11 @@
+use serde_json_helpers::serde_enum_string;
+
+#[serde_enum_string(transform = "snek_case", matching = "fuzzy", display)]
+enum MultipleErrors {
+    #[enum_string(renam = "first")]
+    Variant,
+    #[enum_string(alias)]
+    OtherVariant,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use serde_json_helpers::serde_enum_string;
+
+#[serde_enum_string(repr = "u8")]
+enum Generic<T> {
+    Variant,
+    #[enum_string(skip)]
+    Marker(std::marker::PhantomData<T>),
+}
+
+fn main() {}
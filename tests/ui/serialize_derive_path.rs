@@ -0,0 +1,9 @@
+use serde_json_helpers::serde_enum_string;
+
+#[serde_enum_string(transform = "snake_case")]
+#[derive(Debug, serde::Serialize)]
+enum SerializeByPath {
+    Variant,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use serde_json_helpers::serde_enum_string;
+
+#[serde_enum_string(transfrom = "snake_case", from_str)]
+enum UnknownKey {
+    Variant,
+}
+
+#[serde_enum_string(transform = "snake_case", variant)]
+enum UnknownKeyword {
+    Variant,
+}
+
+fn main() {}
@@ -0,0 +1,112 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0+
+
+use serde::{Deserialize, Serialize};
+use serde_json_helpers::serde_enum_string;
+
+#[serde_enum_string(transform = "kebab-case", unit_variant)]
+#[derive(Debug, PartialEq)]
+enum Level {
+    Low,
+    VeryHigh,
+    #[enum_string(rename = "max", alias = "maximum")]
+    Maximum,
+    #[enum_string(skip)]
+    Internal,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    level: Level,
+}
+
+#[test]
+fn round_trips_through_json() {
+    assert_eq!(
+        serde_json::to_string(&Level::VeryHigh).unwrap(),
+        "\"very-high\""
+    );
+    assert_eq!(
+        serde_json::from_str::<Level>("\"very-high\"").unwrap(),
+        Level::VeryHigh
+    );
+    assert_eq!(
+        serde_json::from_str::<Level>("\"maximum\"").unwrap(),
+        Level::Maximum
+    );
+
+    let err = serde_json::from_str::<Level>("\"high\"")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("one of: \"low\", \"very-high\", \"max\""));
+
+    assert!(serde_json::to_string(&Level::Internal).is_err());
+}
+
+#[test]
+fn round_trips_through_ron() {
+    let config = Config {
+        level: Level::Maximum,
+    };
+
+    // RON writes unit variants as bare identifiers, so the name must be a valid one
+    assert_eq!(ron::to_string(&config).unwrap(), "(level:max)");
+    assert_eq!(ron::from_str::<Config>("(level:max)").unwrap(), config);
+    assert_eq!(ron::from_str::<Level>("low").unwrap(), Level::Low);
+    assert!(ron::from_str::<Level>("\"low\"").is_err());
+}
+
+#[test]
+fn round_trips_through_binary_formats() {
+    let config = Config {
+        level: Level::VeryHigh,
+    };
+
+    let cbor = serde_cbor::to_vec(&config).unwrap();
+    assert_eq!(serde_cbor::from_slice::<Config>(&cbor).unwrap(), config);
+
+    // The packed format identifies variants by index
+    let packed = serde_cbor::ser::to_vec_packed(&config).unwrap();
+    assert!(!packed.windows(9).any(|bytes| bytes == b"very-high"));
+    assert_eq!(serde_cbor::from_slice::<Config>(&packed).unwrap(), config);
+
+    let msgpack = rmp_serde::to_vec(&config).unwrap();
+    assert_eq!(rmp_serde::from_slice::<Config>(&msgpack).unwrap(), config);
+}
+
+#[serde_enum_string(transform = "snake_case", unit_variant, with_module = "status_str")]
+#[derive(Debug, PartialEq)]
+enum Status {
+    Active,
+    #[enum_string(default)]
+    Unrecognised,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Account {
+    #[serde(with = "status_str")]
+    status: Status,
+}
+
+#[test]
+fn falls_back_to_default() {
+    assert_eq!(
+        ron::from_str::<Account>("(status:closed)").unwrap(),
+        Account {
+            status: Status::Unrecognised
+        }
+    );
+
+    // An index past the end of the variants, encoded as CBOR's packed format would
+    assert_eq!(
+        serde_cbor::from_slice::<Account>(b"\xa1\x00\x07").unwrap(),
+        Account {
+            status: Status::Unrecognised
+        }
+    );
+    assert_eq!(
+        serde_cbor::from_slice::<Account>(b"\xa1\x00\x00").unwrap(),
+        Account {
+            status: Status::Active
+        }
+    );
+}
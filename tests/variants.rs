@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0+
+
+mod util;
+
+use serde_json_helpers::serde_enum_string;
+
+test_enum_variant!(TestEnum, (transform = "snake_case", variants));
+
+#[test]
+fn lists_variants_in_declaration_order() {
+    assert_eq!(
+        TestEnum::VARIANTS,
+        &[
+            TestEnum::Variant,
+            TestEnum::ExampleVariant,
+            TestEnum::Example2
+        ]
+    );
+
+    assert_eq!(TestEnum::NAMES, &["variant", "example_variant", "example2"]);
+
+    assert_eq!(
+        TestEnum::iter().cloned().collect::<Vec<TestEnum>>(),
+        TestEnum::VARIANTS
+    );
+}
+
+#[test]
+fn names_match_serialized() {
+    for (variant, name) in TestEnum::iter().zip(TestEnum::NAMES) {
+        assert_eq!(
+            serde_json::to_string(variant).unwrap(),
+            serde_json::to_string(name).unwrap()
+        );
+    }
+}
+
+#[serde_enum_string(transform = "snake_case", variants)]
+#[derive(Debug, PartialEq, Clone)]
+enum TestEnumOther {
+    Variant,
+    #[enum_string(other)]
+    Unknown(String),
+    ExampleVariant,
+}
+
+#[test]
+fn skips_other_variant() {
+    assert_eq!(
+        TestEnumOther::VARIANTS,
+        &[TestEnumOther::Variant, TestEnumOther::ExampleVariant]
+    );
+
+    assert_eq!(TestEnumOther::NAMES, &["variant", "example_variant"]);
+}
@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0+
+
+use serde::{Deserialize, Serialize};
+use serde_json_helpers::serde_enum_string;
+use std::collections::BTreeMap;
+
+#[serde_enum_string(transform = "snake_case", with_module = "level_str")]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+enum Level {
+    Low,
+    VeryHigh,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Thresholds {
+    #[serde(with = "level_str")]
+    level: Level,
+    #[serde(with = "level_str::option")]
+    maybe: Option<Level>,
+    #[serde(with = "level_str::vec")]
+    many: Vec<Level>,
+    #[serde(with = "level_str::map_key")]
+    counts: BTreeMap<Level, u32>,
+}
+
+#[test]
+fn with_module_round_trips_fields() {
+    let mut counts = BTreeMap::new();
+    counts.insert(Level::Low, 1);
+    counts.insert(Level::VeryHigh, 2);
+
+    let thresholds = Thresholds {
+        level: Level::VeryHigh,
+        maybe: None,
+        many: vec![Level::Low, Level::VeryHigh],
+        counts,
+    };
+
+    let json = serde_json::to_string(&thresholds).unwrap();
+
+    assert_eq!(
+        json,
+        r#"{"level":"very_high","maybe":null,"many":["low","very_high"],"counts":{"low":1,"very_high":2}}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<Thresholds>(&json).unwrap(),
+        thresholds
+    );
+
+    let with_some = serde_json::from_str::<Thresholds>(
+        r#"{"level":"low","maybe":"low","many":[],"counts":{}}"#,
+    )
+    .unwrap();
+
+    assert_eq!(with_some.maybe, Some(Level::Low));
+    assert!(serde_json::from_str::<Thresholds>(
+        r#"{"level":"medium","maybe":null,"many":[],"counts":{}}"#
+    )
+    .is_err());
+}
+
+#[test]
+fn impls_match_with_module() {
+    assert_eq!(
+        serde_json::to_string(&Level::VeryHigh).unwrap(),
+        "\"very_high\""
+    );
+    assert_eq!(
+        serde_json::from_str::<Level>("\"low\"").unwrap(),
+        Level::Low
+    );
+}
+
+/// An enum whose own impls come from serde, with the string form only used where asked for
+#[serde_enum_string(transform = "kebab-case", with_module = "shared_str", skip_serde_impls)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+enum Shared {
+    Variant,
+    ExampleVariant,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Both {
+    plain: Shared,
+    #[serde(with = "shared_str")]
+    string: Shared,
+}
+
+#[test]
+fn with_module_works_alongside_serde_impls() {
+    let both = Both {
+        plain: Shared::ExampleVariant,
+        string: Shared::ExampleVariant,
+    };
+
+    let json = serde_json::to_string(&both).unwrap();
+
+    assert_eq!(
+        json,
+        r#"{"plain":"EXAMPLEVARIANT","string":"example-variant"}"#
+    );
+    assert_eq!(serde_json::from_str::<Both>(&json).unwrap(), both);
+}